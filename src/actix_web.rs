@@ -0,0 +1,181 @@
+//! [Actix Web](https://actix.rs) support.
+//!
+//! With this feature enabled, `Roman` deserializes from path segments and query
+//! strings, so it can be taken directly through [`web::Path`](actix_web::web::Path)
+//! or [`web::Query`](actix_web::web::Query); it can also be returned from a handler,
+//! producing a plain-text numeral. [`Error`] implements [`ResponseError`] with a
+//! `400 Bad Request` status, so handlers which parse numerals themselves can use `?`.
+//!
+//! Left to itself, Actix Web answers a numeral which fails to deserialize with its
+//! own error, a `404 Not Found` for a path. Registering [`path_config`] and
+//! [`query_config`] as app data answers it with the [`Error`] instead.
+//!
+//! ```rust
+//! use actix_web::{get, web, App};
+//! use serde::Deserialize;
+//! use xvii::Roman;
+//!
+//! #[get("/chapter/{number}/next")]
+//! async fn next(number: web::Path<Roman>) -> xvii::Result<Roman> {
+//!     Roman::new(number.value() + 1)
+//! }
+//!
+//! #[derive(Deserialize)]
+//! struct Range {
+//!     from: Roman,
+//!     to: Roman,
+//! }
+//!
+//! #[get("/chapters")]
+//! async fn chapters(range: web::Query<Range>) -> String {
+//!     format!("{}..{}", range.from, range.to)
+//! }
+//!
+//! #[get("/value/{numeral}")]
+//! async fn value(numeral: web::Path<String>) -> xvii::Result<String> {
+//!     Ok(numeral.parse::<Roman>()?.value().to_string())
+//! }
+//!
+//! let app = App::new()
+//!     .app_data(xvii::actix_web::path_config())
+//!     .app_data(xvii::actix_web::query_config())
+//!     .service(next)
+//!     .service(chapters)
+//!     .service(value);
+//! ```
+
+use crate::{Error, Roman};
+use actix_web::{
+    body::BoxBody,
+    http::StatusCode,
+    web::{PathConfig, Query, QueryConfig},
+    HttpRequest, HttpResponse, Responder, ResponseError,
+};
+
+/// Returns a [`PathConfig`] under which a [`web::Path`](actix_web::web::Path) which
+/// fails to extract is answered with the [`Error`] from the first segment which is
+/// not a numeral.
+///
+/// This is meant for paths whose parameters are all numerals. If every segment
+/// parses, the failure lay elsewhere, and Actix Web's own error is kept.
+pub fn path_config() -> PathConfig {
+    PathConfig::default().error_handler(|err, req| {
+        match req
+            .match_info()
+            .iter()
+            .find_map(|(_, segment)| segment.parse::<Roman>().err())
+        {
+            Some(e) => e.into(),
+            None => err.into(),
+        }
+    })
+}
+
+/// Returns a [`QueryConfig`] under which a [`web::Query`](actix_web::web::Query)
+/// which fails to extract is answered with the [`Error`] from the first value which
+/// is not a numeral.
+///
+/// As with [`path_config`], this is meant for queries whose fields are all numerals.
+pub fn query_config() -> QueryConfig {
+    QueryConfig::default().error_handler(|err, req| {
+        let pairs = Query::<Vec<(String, String)>>::from_query(req.query_string());
+        match pairs
+            .iter()
+            .flat_map(|pairs| pairs.iter())
+            .find_map(|(_, value)| value.parse::<Roman>().err())
+        {
+            Some(e) => e.into(),
+            None => err.into(),
+        }
+    })
+}
+
+impl ResponseError for Error {
+    fn status_code(&self) -> StatusCode {
+        StatusCode::BAD_REQUEST
+    }
+}
+
+impl Responder for Roman {
+    type Body = BoxBody;
+
+    fn respond_to(self, req: &HttpRequest) -> HttpResponse<BoxBody> {
+        self.to_string().respond_to(req).map_into_boxed_body()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{path_config, query_config};
+    use crate::{Error, Roman};
+    use actix_web::{
+        body,
+        http::StatusCode,
+        rt::System,
+        test::{self, TestRequest},
+        web, App, FromRequest, Responder, ResponseError,
+    };
+
+    async fn value(number: web::Path<Roman>) -> String {
+        number.value().to_string()
+    }
+
+    async fn values(query: web::Query<Vec<(String, Roman)>>) -> String {
+        query.len().to_string()
+    }
+
+    /// Requests `uri` from an app taking numerals through its path and query,
+    /// returning the status and body of the response.
+    fn get(uri: &str) -> (StatusCode, String) {
+        System::new().block_on(async {
+            let app = test::init_service(
+                App::new()
+                    .app_data(path_config())
+                    .app_data(query_config())
+                    .route("/value/{number}", web::get().to(value))
+                    .route("/values", web::get().to(values)),
+            )
+            .await;
+            let res = test::call_service(&app, TestRequest::get().uri(uri).to_request()).await;
+            let status = res.status();
+            let body = body::to_bytes(res.into_body()).await.unwrap();
+            (status, String::from_utf8(body.to_vec()).unwrap())
+        })
+    }
+
+    #[test]
+    fn path_extracts_roman() {
+        let req = TestRequest::default()
+            .param("number", "xiv")
+            .to_http_request();
+        let number = System::new()
+            .block_on(web::Path::<Roman>::extract(&req))
+            .unwrap();
+        assert_eq!(14, number.value());
+    }
+
+    #[test]
+    fn responds_with_numeral() {
+        let req = TestRequest::default().to_http_request();
+        let res = Roman::new(17).unwrap().respond_to(&req);
+        assert_eq!(StatusCode::OK, res.status());
+
+        let bytes = System::new().block_on(body::to_bytes(res.into_body()));
+        assert_eq!(&b"XVII"[..], &*bytes.unwrap());
+    }
+
+    #[test]
+    fn bad_numerals_respond_with_error() {
+        assert_eq!((StatusCode::OK, "14".into()), get("/value/xiv"));
+        let error = Error::InvalidDigit(b'Q', 1).to_string();
+        assert_eq!((StatusCode::BAD_REQUEST, error.clone()), get("/value/XQ"));
+        assert_eq!((StatusCode::OK, "2".into()), get("/values?a=I&b=II"));
+        assert_eq!((StatusCode::BAD_REQUEST, error), get("/values?a=I&b=XQ"));
+    }
+
+    #[test]
+    fn error_is_bad_request() {
+        let res = Error::InvalidDigit(b'Q', 0).error_response();
+        assert_eq!(StatusCode::BAD_REQUEST, res.status());
+    }
+}
@@ -0,0 +1,101 @@
+use crate::{unit, Error, Result, Roman};
+
+/// A user-supplied set of digits for parsing numerals written in other glyphs.
+///
+/// The digits are read with exactly the same additive and subtractive rules as
+/// ordinary numerals; only the mapping from characters to values changes. This
+/// covers alternate glyph sets, transliterations, and look-alike characters from
+/// other scripts which turn up in OCR output.
+///
+/// ## Examples
+///
+/// ```
+/// use xvii::Alphabet;
+///
+/// // Accept Cyrillic look-alikes alongside the Latin letters.
+/// static DIGITS: &[(char, u16)] = &[
+///     ('M', 1000), ('D', 500), ('C', 100), ('С', 100), ('L', 50),
+///     ('X', 10), ('Х', 10), ('V', 5), ('I', 1), ('І', 1),
+/// ];
+/// let alphabet = Alphabet::new(DIGITS);
+///
+/// assert_eq!(alphabet.parse("ХVІІ").unwrap().value(), 17);
+/// assert!(alphabet.parse("xvii").is_err());
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct Alphabet<'a> {
+    digits: &'a [(char, u16)],
+}
+
+impl<'a> Alphabet<'a> {
+    /// The standard digits, `I`, `V`, `X`, `L`, `C`, `D` and `M`, in either case.
+    pub const LATIN: Alphabet<'static> = Alphabet::new(&[
+        ('M', 1000),
+        ('D', 500),
+        ('C', 100),
+        ('L', 50),
+        ('X', 10),
+        ('V', 5),
+        ('I', 1),
+        ('m', 1000),
+        ('d', 500),
+        ('c', 100),
+        ('l', 50),
+        ('x', 10),
+        ('v', 5),
+        ('i', 1),
+    ]);
+
+    /// Creates an alphabet from pairs of characters and their values.
+    ///
+    /// Matching is exact, so upper- and lowercase forms must be listed separately
+    /// if both are to be accepted. Should a character appear more than once, its
+    /// first value is used.
+    pub const fn new(digits: &'a [(char, u16)]) -> Self {
+        Alphabet { digits }
+    }
+
+    /// Parses a numeral written in this alphabet.
+    ///
+    /// Fails with [`Error::InvalidChar`] at the first character which is not one of
+    /// the alphabet's digits.
+    pub fn parse(&self, s: &str) -> Result<Roman> {
+        let digits = s.char_indices().map(|(offset, c)| self.digit(c, offset));
+        Roman::new(unit::sum_units(unit::RomanUnitIterator::from_digits(
+            digits,
+        ))?)
+    }
+
+    fn digit(&self, c: char, offset: usize) -> Result<u16> {
+        self.digits
+            .iter()
+            .find(|&&(digit, _)| digit == c)
+            .map(|&(_, value)| value)
+            .ok_or(Error::InvalidChar(c, offset))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Alphabet;
+    use crate::Error;
+
+    #[test]
+    fn latin_matches_from_str() {
+        for s in &["MCMLXXXIV", "xvii", "iiiiix", "MMMMCMXCIX"] {
+            assert_eq!(s.parse(), Alphabet::LATIN.parse(s));
+        }
+    }
+
+    #[test]
+    fn subtractive_rules_apply() {
+        let alphabet = Alphabet::new(&[('一', 1), ('五', 5), ('十', 10)]);
+        assert_eq!(9, alphabet.parse("一十").unwrap().value());
+        assert_eq!(16, alphabet.parse("十五一").unwrap().value());
+    }
+
+    #[test]
+    fn unknown_char() {
+        assert_eq!(Err(Error::InvalidChar('Q', 1)), Alphabet::LATIN.parse("XQ"));
+    }
+}
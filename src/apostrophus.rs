@@ -0,0 +1,280 @@
+use crate::{
+    roman::ladder,
+    unit::{self, RomanUnitIterator},
+    Error, Result, Roman, Roman32, Style,
+};
+use core::{
+    convert::TryFrom,
+    fmt::{self, Display, Write},
+};
+
+/// The largest number of reversed Cs in a digit which the formatter writes, giving
+/// `CCCCIↃↃↃↃ` for 1,000,000.
+const MAX_DEPTH: u32 = 4;
+
+impl Roman {
+    /// Returns an [`Apostrophus`] which lazily formats a numeral in apostrophus
+    /// notation.
+    ///
+    /// In this older system, 500 is written `IↃ` and 1000 `CIↃ`, with each further
+    /// pair of `C` and reversed `Ↄ` multiplying by ten, so 10,000 is `CCIↃↃ`. Only
+    /// the ordinary digits up to `C` take part in subtraction, so 400 is written
+    /// `CCCC` and 900 `IↃCCCC`.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use xvii::{Roman, Style};
+    ///
+    /// let n = Roman::new(1666).unwrap();
+    /// assert_eq!(n.apostrophus(Style::Upper).to_string(), "CIↃIↃCLXVI");
+    /// assert_eq!(n.apostrophus(Style::Upper).ascii().to_string(), "CI)I)CLXVI");
+    /// ```
+    pub const fn apostrophus(self, style: Style) -> Apostrophus {
+        Apostrophus::new(self.value() as u32, style)
+    }
+
+    /// Parses a numeral which may use apostrophus notation.
+    ///
+    /// Apostrophic digits may be written with `Ↄ` (U+2183), `ↄ` (U+2184), `Ɔ` (U+0186),
+    /// `ɔ` (U+0254) or `)` for the reversed C, and may be freely mixed with ordinary
+    /// digits, including `D` and `M`.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use xvii::Roman;
+    ///
+    /// assert_eq!(Roman::from_apostrophus("CIↃIↃCLXVI").unwrap().value(), 1666);
+    /// assert_eq!(Roman::from_apostrophus("MI)").unwrap().value(), 1500);
+    /// ```
+    pub fn from_apostrophus(s: &str) -> Result<Roman> {
+        u16::try_from(parse(s)?)
+            .map_err(|_| Error::Overflow)
+            .and_then(Roman::new)
+    }
+}
+
+impl Roman32 {
+    /// Returns an [`Apostrophus`] which lazily formats a numeral in apostrophus
+    /// notation, as described under [`Roman::apostrophus`].
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use xvii::{Roman32, Style};
+    ///
+    /// let n = Roman32::new(15_000).unwrap();
+    /// assert_eq!(n.apostrophus(Style::Upper).to_string(), "CCIↃↃIↃↃ");
+    /// ```
+    pub const fn apostrophus(self, style: Style) -> Apostrophus {
+        Apostrophus::new(self.value(), style)
+    }
+
+    /// Parses a numeral which may use apostrophus notation, as described under
+    /// [`Roman::from_apostrophus`].
+    pub fn from_apostrophus(s: &str) -> Result<Roman32> {
+        Roman32::new(parse(s)?)
+    }
+}
+
+/// Lazy formatter for apostrophus notation.
+///
+/// This struct is created by the [`apostrophus`](Roman::apostrophus) methods of
+/// [`Roman`] and [`Roman32`].
+#[derive(Debug, Copy, Clone)]
+pub struct Apostrophus {
+    value: u32,
+    style: Style,
+    ascii: bool,
+}
+
+impl Apostrophus {
+    const fn new(value: u32, style: Style) -> Self {
+        Apostrophus {
+            value,
+            style,
+            ascii: false,
+        }
+    }
+
+    /// Writes `)` in place of the reversed C, for plain-text environments.
+    pub const fn ascii(self) -> Self {
+        Apostrophus {
+            ascii: true,
+            ..self
+        }
+    }
+}
+
+impl Display for Apostrophus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let (c, i, reversed) = match (self.style, self.ascii) {
+            (Style::Upper, false) | (Style::Clock, false) => ('C', 'I', 'Ↄ'),
+            (Style::Lower, false) => ('c', 'i', 'ↄ'),
+            (Style::Unicode, false) => ('Ⅽ', 'Ⅰ', 'Ↄ'),
+            (Style::Upper, true) | (Style::Unicode, true) | (Style::Clock, true) => ('C', 'I', ')'),
+            (Style::Lower, true) => ('c', 'i', ')'),
+        };
+
+        let mut current = self.value;
+        for depth in (1..=MAX_DEPTH).rev() {
+            let full = 10_u32.pow(depth + 2);
+            for (value, leading) in [(full, depth), (full / 2, 0)] {
+                while current >= value {
+                    for _ in 0..leading {
+                        f.write_char(c)?;
+                    }
+                    f.write_char(i)?;
+                    for _ in 0..depth {
+                        f.write_char(reversed)?;
+                    }
+                    current -= value;
+                }
+            }
+        }
+
+        // Below 500 only the ordinary digits up to C remain.
+        let style = match (self.style, self.ascii) {
+            (Style::Unicode, true) => Style::Upper,
+            (style, _) => style,
+        };
+        for entry in ladder::VALUES.iter().filter(|entry| entry.value <= 100) {
+            while current >= u32::from(entry.value) {
+                f.write_str(entry.digits(style))?;
+                current -= u32::from(entry.value);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn parse(s: &str) -> Result<u32> {
+    unit::sum_units(RomanUnitIterator::from_digits(Digits {
+        rest: s,
+        offset: 0,
+    }))
+}
+
+/// Iterates the digit values of a numeral, reading apostrophic digits as one.
+struct Digits<'a> {
+    rest: &'a str,
+    offset: usize,
+}
+
+impl Iterator for Digits<'_> {
+    type Item = Result<u32>;
+
+    fn next(&mut self) -> Option<Result<u32>> {
+        let c = self.rest.chars().next()?;
+        let offset = self.offset;
+        if let Some((value, len)) = apostrophic(self.rest) {
+            self.rest = &self.rest[len..];
+            self.offset += len;
+            return Some(value);
+        }
+
+        self.rest = &self.rest[c.len_utf8()..];
+        self.offset += c.len_utf8();
+        Some(match u8::try_from(c) {
+            Ok(u) if u.is_ascii() => unit::digit_at(u, offset).map(u32::from),
+            _ => Err(Error::InvalidChar(c, offset)),
+        })
+    }
+}
+
+/// Reads an apostrophic digit at the start of `s`, returning its value and length.
+///
+/// Returns `None` if `s` does not begin with one, including when it begins with a
+/// `C` which belongs to the ordinary digits before one.
+fn apostrophic(s: &str) -> Option<(Result<u32>, usize)> {
+    let leading = s
+        .bytes()
+        .take_while(|b| b.eq_ignore_ascii_case(&b'c'))
+        .count();
+    let mut chars = s[leading..].chars();
+    if !chars.next()?.eq_ignore_ascii_case(&'i') {
+        return None;
+    }
+
+    let depth = chars.take_while(|&c| is_reversed(c)).count();
+    let len = leading
+        + 1
+        + s[leading + 1..]
+            .chars()
+            .take(depth)
+            .map(char::len_utf8)
+            .sum::<usize>();
+    let value = match (depth, leading) {
+        (0, _) => return None,
+        (_, 0) => 10_u32
+            .checked_pow(depth as u32 + 1)
+            .and_then(|n| n.checked_mul(5)),
+        _ if leading == depth => 10_u32.checked_pow(depth as u32 + 2),
+        _ => return None,
+    };
+
+    Some((value.ok_or(Error::Overflow), len))
+}
+
+fn is_reversed(c: char) -> bool {
+    matches!(c, 'Ↄ' | 'ↄ' | 'Ɔ' | 'ɔ' | ')')
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Error, Roman, Roman32, Style};
+
+    fn format(n: u32) -> String {
+        Roman32::new(n)
+            .unwrap()
+            .apostrophus(Style::Upper)
+            .to_string()
+    }
+
+    #[test]
+    fn formats_large_digits() {
+        assert_eq!("IↃ", format(500));
+        assert_eq!("CIↃ", format(1000));
+        assert_eq!("IↃↃↃ", format(50_000));
+        assert_eq!("CCCCIↃↃↃↃIↃↃↃↃ", format(1_500_000));
+        assert_eq!("IↃCCCCXCIX", format(999));
+    }
+
+    #[test]
+    fn round_trips() {
+        for n in (1..=4_999_999).step_by(997) {
+            assert_eq!(
+                Ok(n),
+                Roman32::from_apostrophus(&format(n)).map(Roman32::value)
+            );
+        }
+    }
+
+    #[test]
+    fn parses_fallbacks() {
+        assert_eq!(
+            Ok(10_000),
+            Roman32::from_apostrophus("ccIƆɔ").map(Roman32::value)
+        );
+        assert_eq!(Ok(900), Roman::from_apostrophus("CCI)").map(Roman::value));
+        assert_eq!(Ok(600), Roman::from_apostrophus("i)c").map(Roman::value));
+    }
+
+    #[test]
+    fn rejects_bad_input() {
+        assert_eq!(
+            Err(Error::InvalidChar('Ↄ', 1)),
+            Roman::from_apostrophus("XↃ")
+        );
+        assert_eq!(
+            Err(Error::OutOfRange(10_000)),
+            Roman::from_apostrophus("CCIↃↃ")
+        );
+        assert_eq!(
+            Err(Error::Overflow),
+            Roman32::from_apostrophus("IↃↃↃↃↃↃↃↃↃ")
+        );
+    }
+}
@@ -0,0 +1,297 @@
+use crate::Roman;
+use core::{
+    iter::{Product, Sum},
+    ops::{Add, AddAssign, Div, Mul, Sub, SubAssign},
+};
+
+impl Roman {
+    /// Adds two numerals, returning `None` if the sum is out of range.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use xvii::Roman;
+    ///
+    /// let chapter = Roman::new(17).unwrap();
+    /// assert_eq!(chapter.checked_add(Roman::MIN).unwrap().value(), 18);
+    /// assert_eq!(Roman::MAX.checked_add(chapter), None);
+    /// ```
+    pub const fn checked_add(self, rhs: Roman) -> Option<Roman> {
+        // Neither value is above 4999, so the sum fits a `u16`.
+        in_range(self.value() + rhs.value())
+    }
+
+    /// Subtracts one numeral from another, returning `None` if the difference is zero
+    /// or negative.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use xvii::Roman;
+    ///
+    /// let chapter = Roman::new(17).unwrap();
+    /// assert_eq!(chapter.checked_sub(Roman::MIN).unwrap().value(), 16);
+    /// assert_eq!(chapter.checked_sub(chapter), None);
+    /// ```
+    pub const fn checked_sub(self, rhs: Roman) -> Option<Roman> {
+        match self.value().checked_sub(rhs.value()) {
+            Some(n) => in_range(n),
+            None => None,
+        }
+    }
+
+    /// Adds two numerals, stopping at [`Roman::MAX`].
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use xvii::Roman;
+    ///
+    /// let page = Roman::new(4990).unwrap();
+    /// assert_eq!(page.saturating_add(Roman::new(5).unwrap()).value(), 4995);
+    /// assert_eq!(page.saturating_add(page), Roman::MAX);
+    /// ```
+    pub const fn saturating_add(self, rhs: Roman) -> Roman {
+        Roman::new_saturating(self.value() + rhs.value())
+    }
+
+    /// Subtracts one numeral from another, stopping at [`Roman::MIN`].
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use xvii::Roman;
+    ///
+    /// let page = Roman::new(3).unwrap();
+    /// assert_eq!(page.saturating_sub(Roman::MIN).value(), 2);
+    /// assert_eq!(page.saturating_sub(page), Roman::MIN);
+    /// ```
+    pub const fn saturating_sub(self, rhs: Roman) -> Roman {
+        Roman::new_saturating(self.value().saturating_sub(rhs.value()))
+    }
+
+    /// Multiplies two numerals, returning `None` if the product is out of range.
+    pub const fn checked_mul(self, rhs: Roman) -> Option<Roman> {
+        match self.value().checked_mul(rhs.value()) {
+            Some(n) => in_range(n),
+            None => None,
+        }
+    }
+
+    /// Divides one numeral by another, discarding any remainder, and returning `None`
+    /// if the quotient is zero.
+    pub const fn checked_div(self, rhs: Roman) -> Option<Roman> {
+        in_range(self.value() / rhs.value())
+    }
+}
+
+// As with the primitive integers, a result out of range is a panic, and the checked
+// methods are there for when it is not a bug.
+macro_rules! op {
+    ($Op:ident, $op:ident, $checked:ident, $msg:literal) => {
+        impl $Op for Roman {
+            type Output = Roman;
+
+            #[track_caller]
+            fn $op(self, rhs: Roman) -> Roman {
+                self.$checked(rhs).expect($msg)
+            }
+        }
+
+        impl $Op<u16> for Roman {
+            type Output = Roman;
+
+            #[track_caller]
+            fn $op(self, rhs: u16) -> Roman {
+                self.value().$checked(rhs).and_then(in_range).expect($msg)
+            }
+        }
+    };
+}
+
+op!(Add, add, checked_add, "numeral addition out of range");
+op!(Sub, sub, checked_sub, "numeral subtraction out of range");
+op!(Mul, mul, checked_mul, "numeral multiplication out of range");
+op!(Div, div, checked_div, "numeral division out of range");
+
+macro_rules! op_assign {
+    ($OpAssign:ident, $op_assign:ident, $op:ident) => {
+        impl $OpAssign for Roman {
+            #[track_caller]
+            fn $op_assign(&mut self, rhs: Roman) {
+                *self = self.$op(rhs);
+            }
+        }
+
+        impl $OpAssign<u16> for Roman {
+            #[track_caller]
+            fn $op_assign(&mut self, rhs: u16) {
+                *self = self.$op(rhs);
+            }
+        }
+    };
+}
+
+op_assign!(AddAssign, add_assign, add);
+op_assign!(SubAssign, sub_assign, sub);
+
+// Totals of numerals are rarely numerals themselves, so they are summed into wider
+// integers, which overflow as the integers themselves would.
+macro_rules! accumulate {
+    ($($t:ty),*) => {$(
+        impl Sum<Roman> for $t {
+            fn sum<I: Iterator<Item = Roman>>(iter: I) -> $t {
+                iter.map(<$t>::from).sum()
+            }
+        }
+
+        impl<'a> Sum<&'a Roman> for $t {
+            fn sum<I: Iterator<Item = &'a Roman>>(iter: I) -> $t {
+                iter.copied().sum()
+            }
+        }
+
+        impl Product<Roman> for $t {
+            fn product<I: Iterator<Item = Roman>>(iter: I) -> $t {
+                iter.map(<$t>::from).product()
+            }
+        }
+
+        impl<'a> Product<&'a Roman> for $t {
+            fn product<I: Iterator<Item = &'a Roman>>(iter: I) -> $t {
+                iter.copied().product()
+            }
+        }
+    )*};
+}
+
+accumulate!(u32, u64);
+
+/// Sums numerals into a numeral, which is `None` if the total is out of range or
+/// there are no numerals to sum.
+///
+/// ## Examples
+///
+/// ```
+/// use xvii::Roman;
+///
+/// let pages = [Roman::new(12).unwrap(), Roman::new(30).unwrap()];
+/// let total: Option<Roman> = pages.iter().copied().sum();
+/// assert_eq!(total.unwrap().to_string(), "XLII");
+/// assert_eq!(pages.iter().copied().sum::<u32>(), 42);
+///
+/// let too_many: Option<Roman> = [Roman::MAX, Roman::MIN].iter().copied().sum();
+/// assert_eq!(too_many, None);
+/// ```
+impl Sum<Roman> for Option<Roman> {
+    fn sum<I: Iterator<Item = Roman>>(mut iter: I) -> Option<Roman> {
+        let first = iter.next()?;
+        iter.try_fold(first, Roman::checked_add)
+    }
+}
+
+const fn in_range(n: u16) -> Option<Roman> {
+    match Roman::new(n) {
+        Ok(n) => Some(n),
+        Err(_) => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Roman;
+
+    fn roman(n: u16) -> Roman {
+        Roman::new(n).unwrap()
+    }
+
+    #[test]
+    fn checked() {
+        assert_eq!(Some(roman(4999)), roman(4998).checked_add(roman(1)));
+        assert_eq!(None, roman(4999).checked_add(roman(1)));
+        assert_eq!(None, Roman::MAX.checked_add(Roman::MAX));
+        assert_eq!(Some(roman(1)), roman(2).checked_sub(roman(1)));
+        assert_eq!(None, roman(1).checked_sub(roman(1)));
+        assert_eq!(None, roman(1).checked_sub(roman(2)));
+        assert_eq!(Some(roman(4900)), roman(49).checked_mul(roman(100)));
+        assert_eq!(None, roman(50).checked_mul(roman(100)));
+        assert_eq!(Some(roman(3)), roman(7).checked_div(roman(2)));
+        assert_eq!(None, roman(1).checked_div(roman(2)));
+    }
+
+    #[test]
+    fn saturating() {
+        assert_eq!(Roman::MIN, Roman::new_saturating(0));
+        assert_eq!(roman(4999), Roman::new_saturating(4999));
+        assert_eq!(Roman::MAX, Roman::new_saturating(u16::MAX));
+        assert_eq!(Roman::MAX, Roman::MAX.saturating_add(Roman::MAX));
+        assert_eq!(roman(4999), roman(4998).saturating_add(roman(1)));
+        assert_eq!(Roman::MIN, Roman::MIN.saturating_sub(Roman::MAX));
+        assert_eq!(roman(1), roman(2).saturating_sub(roman(1)));
+    }
+
+    #[test]
+    fn sum_and_product() {
+        let numerals = [roman(10), roman(20), roman(30)];
+        assert_eq!(60, numerals.iter().sum::<u32>());
+        assert_eq!(60, numerals.iter().copied().sum::<u64>());
+        assert_eq!(6000, numerals.iter().product::<u32>());
+        assert_eq!(6000, numerals.iter().copied().product::<u64>());
+
+        assert_eq!(Some(roman(60)), numerals.iter().copied().sum());
+        assert_eq!(None, [].iter().copied().sum::<Option<Roman>>());
+        assert_eq!(
+            None,
+            [Roman::MAX, Roman::MIN]
+                .iter()
+                .copied()
+                .sum::<Option<Roman>>()
+        );
+    }
+
+    #[test]
+    fn operators() {
+        let chapter = roman(17);
+        assert_eq!(roman(18), chapter + 1);
+        assert_eq!(roman(34), chapter + chapter);
+        assert_eq!(roman(16), chapter - 1);
+        assert_eq!(roman(1), chapter - roman(16));
+        assert_eq!(roman(51), chapter * 3);
+        assert_eq!(roman(289), chapter * chapter);
+        assert_eq!(roman(8), chapter / 2);
+        assert_eq!(roman(1), chapter / chapter);
+    }
+
+    #[test]
+    fn assignment() {
+        let mut section = roman(1);
+        for _ in 0..3 {
+            section += 1;
+        }
+        assert_eq!(roman(4), section);
+        section += section;
+        assert_eq!(roman(8), section);
+        section -= 2;
+        assert_eq!(roman(6), section);
+        section -= roman(5);
+        assert_eq!(Roman::MIN, section);
+    }
+
+    #[test]
+    #[should_panic(expected = "numeral addition out of range")]
+    fn add_out_of_range() {
+        let _ = Roman::MAX + 1;
+    }
+
+    #[test]
+    #[should_panic(expected = "numeral subtraction out of range")]
+    fn sub_to_zero() {
+        let _ = Roman::MIN - Roman::MIN;
+    }
+
+    #[test]
+    #[should_panic(expected = "numeral division out of range")]
+    fn div_by_zero() {
+        let _ = Roman::MAX / 0;
+    }
+}
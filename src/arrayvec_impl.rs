@@ -0,0 +1,38 @@
+use crate::{Roman, Style};
+use arrayvec::ArrayString;
+use core::fmt::Write;
+
+impl Roman {
+    /// Formats `self` into an [`ArrayString`] large enough for any numeral, without
+    /// allocating.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use xvii::{Roman, Style};
+    ///
+    /// let s = Roman::new(1984).unwrap().to_arraystring(Style::Lower);
+    /// assert_eq!(s.as_str(), "mcmlxxxiv");
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "arrayvec")))]
+    pub fn to_arraystring(self, style: Style) -> ArrayString<{ Roman::MAX_FORMATTED_LEN }> {
+        let mut s = ArrayString::new();
+        write!(s, "{}", self.format(style)).expect("numeral longer than MAX_FORMATTED_LEN");
+        s
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Roman, Style};
+    use arrayvec::ArrayString;
+
+    #[test]
+    fn formats_in_style() {
+        let n = Roman::new(1994).unwrap();
+        let s: ArrayString<{ Roman::MAX_FORMATTED_LEN }> = n.to_arraystring(Style::Lower);
+        assert_eq!("mcmxciv", s.as_str());
+        assert_eq!(Roman::MAX_FORMATTED_LEN, s.capacity());
+        assert_eq!("MCMXCIIII", n.to_arraystring(Style::Clock).as_str());
+    }
+}
@@ -0,0 +1,165 @@
+//! [Apache Arrow](https://docs.rs/arrow) support for converting whole columns.
+//!
+//! Numerals are held in Arrow as [`UInt16Array`], which keeps columns compact and
+//! lets the rest of an analytics pipeline treat them as ordinary integers. The
+//! [`parse_numerals`] and [`format_numerals`] kernels convert between such arrays and
+//! string arrays in a single pass, and [`RomanBuilder`] builds one value at a time.
+//! Nulls are carried through unchanged.
+//!
+//! ```rust
+//! use arrow_array::{Array, StringArray};
+//! use xvii::{arrow, Style};
+//!
+//! let strings = StringArray::from(vec![Some("XVII"), None, Some("mcmlxxxiv")]);
+//! let values = arrow::parse_numerals(&strings).unwrap();
+//! assert_eq!(values.value(0), 17);
+//! assert!(values.is_null(1));
+//! assert_eq!(values.value(2), 1984);
+//!
+//! let strings = arrow::format_numerals(&values, Style::Lower).unwrap();
+//! assert_eq!(strings.value(0), "xvii");
+//! assert_eq!(strings.value(2), "mcmlxxxiv");
+//! ```
+
+use crate::{Error, Result, Roman, Style};
+use arrow_array::{
+    builder::{ArrayBuilder, StringBuilder, UInt16Builder},
+    Array, GenericStringArray, OffsetSizeTrait, StringArray, UInt16Array,
+};
+use arrow_schema::ArrowError;
+use std::fmt::Write;
+
+/// Builds a [`UInt16Array`] of validated numeral values.
+#[derive(Debug, Default)]
+pub struct RomanBuilder {
+    values: UInt16Builder,
+}
+
+impl RomanBuilder {
+    /// Creates an empty builder.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates an empty builder with room for `capacity` values.
+    pub fn with_capacity(capacity: usize) -> Self {
+        RomanBuilder {
+            values: UInt16Builder::with_capacity(capacity),
+        }
+    }
+
+    /// Appends a numeral.
+    pub fn append_value(&mut self, roman: Roman) {
+        self.values.append_value(roman.value());
+    }
+
+    /// Appends a null.
+    pub fn append_null(&mut self) {
+        self.values.append_null();
+    }
+
+    /// Appends a numeral, or a null for `None`.
+    pub fn append_option(&mut self, roman: Option<Roman>) {
+        self.values.append_option(roman.map(Roman::value));
+    }
+
+    /// Parses and appends a numeral string, leaving the builder unchanged on error.
+    pub fn append_str(&mut self, s: &str) -> Result<()> {
+        self.append_value(s.parse()?);
+        Ok(())
+    }
+
+    /// Returns the number of values appended so far.
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Returns `true` if no values have been appended.
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Builds the array and resets the builder.
+    pub fn finish(&mut self) -> UInt16Array {
+        self.values.finish()
+    }
+}
+
+/// Parses an array of numeral strings into an array of their values.
+///
+/// Fails on the first string which is not a valid numeral, naming its row.
+pub fn parse_numerals<O: OffsetSizeTrait>(
+    strings: &GenericStringArray<O>,
+) -> Result<UInt16Array, ArrowError> {
+    let mut builder = RomanBuilder::with_capacity(strings.len());
+
+    for (row, s) in strings.iter().enumerate() {
+        match s {
+            Some(s) => builder.append_str(s).map_err(|e| row_error(row, e))?,
+            None => builder.append_null(),
+        }
+    }
+
+    Ok(builder.finish())
+}
+
+/// Formats an array of values as numeral strings in the given style.
+///
+/// Fails on the first value outside the range `1..=4999`, naming its row.
+pub fn format_numerals(values: &UInt16Array, style: Style) -> Result<StringArray, ArrowError> {
+    // Most numerals in real data are short; this is a hint, not a limit.
+    let mut builder = StringBuilder::with_capacity(values.len(), values.len() * 4);
+
+    for (row, value) in values.iter().enumerate() {
+        match value {
+            Some(value) => {
+                let roman = Roman::new(value).map_err(|e| row_error(row, e))?;
+                write!(builder, "{}", roman.format(style))
+                    .expect("writing to a StringBuilder cannot fail");
+                builder.append_value("");
+            }
+            None => builder.append_null(),
+        }
+    }
+
+    Ok(builder.finish())
+}
+
+fn row_error(row: usize, e: Error) -> ArrowError {
+    ArrowError::ComputeError(format!("Row {}: {}", row, e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{format_numerals, parse_numerals, RomanBuilder};
+    use crate::{Roman, Style};
+    use arrow_array::{Array, LargeStringArray, UInt16Array};
+
+    #[test]
+    fn parse_reports_row() {
+        let strings = LargeStringArray::from(vec!["I", "II", "IIQ"]);
+        let e = parse_numerals(&strings).unwrap_err();
+        assert!(e.to_string().contains("Row 2"), "{}", e);
+    }
+
+    #[test]
+    fn format_rejects_zero() {
+        let values = UInt16Array::from(vec![Some(1), None, Some(0)]);
+        let e = format_numerals(&values, Style::Upper).unwrap_err();
+        assert!(e.to_string().contains("Row 2"), "{}", e);
+    }
+
+    #[test]
+    fn builder_keeps_nulls() {
+        let mut builder = RomanBuilder::new();
+        builder.append_value(Roman::new(4).unwrap());
+        builder.append_option(None);
+        assert!(builder.append_str("nope").is_err());
+        assert_eq!(2, builder.len());
+
+        let values = builder.finish();
+        assert_eq!(4, values.value(0));
+        assert!(values.is_null(1));
+        assert!(builder.is_empty());
+    }
+}
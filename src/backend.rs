@@ -0,0 +1,48 @@
+use crate::{GenericRoman, Result, Style};
+use core::{
+    fmt::{self, Debug},
+    hash::Hash,
+};
+
+pub(crate) mod sealed {
+    pub trait Sealed {}
+}
+
+/// An integer type which can back a [`GenericRoman`].
+///
+/// Each backend fixes the range of its numerals and the notation used to write
+/// them: `u8` gives [`Roman8`](crate::Roman8), up to 255 in a single byte; `u16`
+/// gives [`Roman`](crate::Roman), with the seven standard digits up to 4999; `u32`
+/// gives [`Roman32`](crate::Roman32), adding the vinculum; and `u64` gives
+/// [`RomanLarge`](crate::RomanLarge), adding the double vinculum. This trait is
+/// sealed, and its methods are only there to let the generic impls reach the
+/// concrete ones.
+///
+/// Every backend fails in the same way, so that generic code can match on the
+/// errors. Zero, whether passed to `new` or parsed from an empty string, is
+/// [`Error::OutOfRange(0)`](crate::Error::OutOfRange), as for `Roman::new`. A value
+/// above `MAX` is `OutOfRange` holding it if it fits a `u16`, and
+/// [`Error::Overflow`](crate::Error::Overflow) if not.
+pub trait RomanBackend: Copy + Ord + sealed::Sealed {
+    /// The non-zero counterpart of the backing type, in which the value is stored so
+    /// that `Option<GenericRoman<T>>` is no larger than `T`.
+    type NonZero: Copy + Debug + Eq + Ord + Hash;
+
+    /// The largest value which can be written in this backend's notation.
+    const MAX: Self;
+
+    #[doc(hidden)]
+    const NAME: &'static str;
+
+    #[doc(hidden)]
+    fn new(n: Self) -> Result<GenericRoman<Self>>;
+
+    #[doc(hidden)]
+    fn get(n: GenericRoman<Self>) -> Self;
+
+    #[doc(hidden)]
+    fn fmt(n: GenericRoman<Self>, style: Style, f: &mut fmt::Formatter) -> fmt::Result;
+
+    #[doc(hidden)]
+    fn parse(s: &str) -> Result<GenericRoman<Self>>;
+}
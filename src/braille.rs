@@ -0,0 +1,90 @@
+use crate::{roman::ladder, Roman, Style};
+use core::fmt::{self, Display, Write};
+
+/// Grade 1 symbol indicator, dots 56.
+const GRADE_1: char = '\u{2830}';
+/// Capital letter indicator, dot 6.
+const CAPITAL: char = '\u{2820}';
+
+impl Roman {
+    /// Returns a [`Braille`] which lazily transcribes a numeral into Unicode braille
+    /// patterns, following Unified English Braille.
+    ///
+    /// Roman numerals are brailled as ordinary letters. So that the letters cannot be
+    /// read as contractions (`X` alone is the wordsign for "it", and `CD` the shortform
+    /// for "could"), the transcription opens with a grade 1 indicator: a symbol
+    /// indicator for a single letter, or a word indicator for several. In uppercase
+    /// and Unicode styles, a capital letter or capitals word indicator follows.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use xvii::{Roman, Style};
+    ///
+    /// let seventeen = Roman::new(17).unwrap();
+    /// assert_eq!(seventeen.braille(Style::Lower).to_string(), "⠰⠰⠭⠧⠊⠊");
+    /// assert_eq!(seventeen.braille(Style::Upper).to_string(), "⠰⠰⠠⠠⠭⠧⠊⠊");
+    /// assert_eq!(Roman::new(5).unwrap().braille(Style::Upper).to_string(), "⠰⠠⠧");
+    /// ```
+    pub const fn braille(self, style: Style) -> Braille {
+        Braille { roman: self, style }
+    }
+}
+
+/// Lazy braille formatter.
+///
+/// This struct is created by the [`braille`](Roman::braille) method.
+#[derive(Debug, Copy, Clone)]
+pub struct Braille {
+    roman: Roman,
+    style: Style,
+}
+
+impl Display for Braille {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let indicators = match ladder::formatted_len(self.roman.value()) {
+            1 => 1,
+            _ => 2,
+        };
+
+        for _ in 0..indicators {
+            f.write_char(GRADE_1)?;
+        }
+        if let Style::Upper | Style::Unicode | Style::Clock = self.style {
+            for _ in 0..indicators {
+                f.write_char(CAPITAL)?;
+            }
+        }
+
+        let mut current = self.roman.value();
+        for entry in ladder::VALUES {
+            while current >= entry.value {
+                match (self.style, entry.value) {
+                    (Style::Clock, 4) => f.write_str("⠊⠊⠊⠊")?,
+                    _ => f.write_str(entry.braille)?,
+                }
+                current -= entry.value;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Roman, Style};
+
+    #[test]
+    fn every_letter() {
+        let n = Roman::new(1666).unwrap();
+        assert_eq!("⠰⠰⠍⠙⠉⠇⠭⠧⠊", n.braille(Style::Lower).to_string());
+    }
+
+    #[test]
+    fn single_letter() {
+        let n = Roman::new(1000).unwrap();
+        assert_eq!("⠰⠍", n.braille(Style::Lower).to_string());
+        assert_eq!("⠰⠠⠍", n.braille(Style::Upper).to_string());
+    }
+}
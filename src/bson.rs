@@ -0,0 +1,174 @@
+//! [BSON](https://docs.rs/bson) support for storing numerals in MongoDB.
+//!
+//! A `Roman` converts to and from [`Bson`] directly, in which case it is stored as
+//! an `Int32` so that it sorts and compares numerically inside the database. For
+//! serde-derived documents, the [`int32`] and [`string`] modules can be used with
+//! `#[serde(with = "...")]` to choose the stored representation explicitly:
+//!
+//! ```rust
+//! use serde::{Deserialize, Serialize};
+//! use xvii::Roman;
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Chapter {
+//!     #[serde(with = "xvii::bson::int32")]
+//!     number: Roman,
+//!     #[serde(with = "xvii::bson::string")]
+//!     label: Roman,
+//! }
+//!
+//! let chapter = Chapter {
+//!     number: Roman::new(14).unwrap(),
+//!     label: Roman::new(14).unwrap(),
+//! };
+//! let doc = bson::serialize_to_document(&chapter).unwrap();
+//! assert_eq!(doc.get_i32("number").unwrap(), 14);
+//! assert_eq!(doc.get_str("label").unwrap(), "XIV");
+//! ```
+//!
+//! Either module reads back both integers and numeral strings, so a collection
+//! may be migrated from one representation to the other without breaking readers.
+
+use crate::{Error, Roman};
+use bson::{spec::ElementType, Bson};
+use std::{
+    convert::TryFrom,
+    fmt::{self, Display},
+};
+
+impl From<Roman> for Bson {
+    fn from(roman: Roman) -> Bson {
+        Bson::Int32(roman.value().into())
+    }
+}
+
+impl TryFrom<Bson> for Roman {
+    type Error = BsonError;
+
+    fn try_from(bson: Bson) -> Result<Roman, BsonError> {
+        let roman = match bson {
+            Bson::Int32(n) => from_int(n.into()),
+            Bson::Int64(n) => from_int(n),
+            Bson::String(s) => s.parse(),
+            other => return Err(BsonError::UnexpectedType(other.element_type())),
+        };
+        roman.map_err(BsonError::Numeral)
+    }
+}
+
+/// An error in converting a [`Bson`] value to a `Roman`.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum BsonError {
+    /// The value was neither an integer nor a string.
+    UnexpectedType(ElementType),
+
+    /// The value was not a valid numeral.
+    Numeral(Error),
+}
+
+impl Display for BsonError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            BsonError::UnexpectedType(kind) => {
+                write!(f, "Expected an integer or a string, found {:?}", kind)
+            }
+            BsonError::Numeral(e) => e.fmt(f),
+        }
+    }
+}
+
+impl std::error::Error for BsonError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            BsonError::UnexpectedType(_) => None,
+            BsonError::Numeral(e) => Some(e),
+        }
+    }
+}
+
+/// Stores a `Roman` as a BSON `Int32`.
+///
+/// For use with `#[serde(with = "xvii::bson::int32")]`.
+pub mod int32 {
+    use crate::{serde_impl::RomanVisitor, Roman};
+    use serde::{Deserializer, Serializer};
+
+    /// Serializes a `Roman` as an `Int32`.
+    pub fn serialize<S: Serializer>(roman: &Roman, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_i32(roman.value().into())
+    }
+
+    /// Deserializes a `Roman` from an integer or a numeral string.
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Roman, D::Error> {
+        deserializer.deserialize_any(RomanVisitor)
+    }
+}
+
+/// Stores a `Roman` as a BSON string holding the uppercase numeral.
+///
+/// For use with `#[serde(with = "xvii::bson::string")]`.
+pub mod string {
+    use crate::{serde_impl::RomanVisitor, Roman};
+    use serde::{Deserializer, Serializer};
+
+    /// Serializes a `Roman` as an uppercase numeral string.
+    pub fn serialize<S: Serializer>(roman: &Roman, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(roman)
+    }
+
+    /// Deserializes a `Roman` from a numeral string or an integer.
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Roman, D::Error> {
+        deserializer.deserialize_any(RomanVisitor)
+    }
+}
+
+fn from_int(n: i64) -> Result<Roman, Error> {
+    u16::try_from(n)
+        .map_err(|_| Error::Overflow)
+        .and_then(Roman::new)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::BsonError;
+    use crate::{Error, Roman};
+    use bson::{doc, spec::ElementType, Bson};
+    use serde::Deserialize;
+    use std::convert::TryFrom;
+
+    #[derive(Deserialize)]
+    struct Chapter {
+        #[serde(with = "super::int32")]
+        number: Roman,
+    }
+
+    #[test]
+    fn bson_round_trip() {
+        let roman = Roman::new(1984).unwrap();
+        assert_eq!(Bson::Int32(1984), Bson::from(roman));
+        assert_eq!(Ok(roman), Roman::try_from(Bson::from(roman)));
+    }
+
+    #[test]
+    fn try_from_rejects_other_types() {
+        assert_eq!(
+            Err(BsonError::UnexpectedType(ElementType::Boolean)),
+            Roman::try_from(Bson::Boolean(true))
+        );
+        assert_eq!(
+            Err(BsonError::Numeral(Error::OutOfRange(0))),
+            Roman::try_from(Bson::Int64(0))
+        );
+    }
+
+    #[test]
+    fn int32_reads_numeral_strings() {
+        let chapter: Chapter = bson::deserialize_from_document(doc! { "number": "XIV" }).unwrap();
+        assert_eq!(14, chapter.number.value());
+
+        let chapter: Chapter = bson::deserialize_from_document(doc! { "number": 14_i64 }).unwrap();
+        assert_eq!(14, chapter.number.value());
+
+        assert!(bson::deserialize_from_document::<Chapter>(doc! { "number": -1 }).is_err());
+    }
+}
@@ -0,0 +1,86 @@
+use crate::{Roman, Style};
+use core::fmt::{self, Display};
+
+/// The error returned by [`Roman::write_buf`] when the buffer cannot hold the
+/// numeral.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct BufTooSmall {
+    needed: usize,
+}
+
+impl BufTooSmall {
+    /// Returns the number of bytes the numeral needed.
+    pub const fn needed(&self) -> usize {
+        self.needed
+    }
+}
+
+impl Display for BufTooSmall {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Buffer too small: {} bytes needed", self.needed)
+    }
+}
+
+impl core::error::Error for BufTooSmall {}
+
+impl Roman {
+    /// Formats `self` into the start of `buf`, returning the number of bytes written.
+    ///
+    /// Nothing is written unless the whole numeral fits. A buffer of
+    /// [`Roman::MAX_FORMATTED_LEN`] bytes is always large enough.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use xvii::{Roman, Style};
+    ///
+    /// let mut buf = [0; 8];
+    /// let n = Roman::new(17).unwrap();
+    /// let len = n.write_buf(&mut buf, Style::Lower).unwrap();
+    /// assert_eq!(&buf[..len], b"xvii");
+    ///
+    /// let err = Roman::new(1984).unwrap().write_buf(&mut buf, Style::Upper).unwrap_err();
+    /// assert_eq!(err.needed(), 9);
+    /// ```
+    pub fn write_buf(self, buf: &mut [u8], style: Style) -> Result<usize, BufTooSmall> {
+        let (bytes, len) = self.to_array(style);
+        match buf.get_mut(..len) {
+            Some(buf) => {
+                buf.copy_from_slice(&bytes[..len]);
+                Ok(len)
+            }
+            None => Err(BufTooSmall { needed: len }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::BufTooSmall;
+    use crate::{Roman, Style};
+
+    #[test]
+    fn exact_fit() {
+        let n = Roman::new(4888).unwrap();
+        let mut buf = [0; Roman::MAX_FORMATTED_LEN];
+        let len = n.write_buf(&mut buf, Style::Unicode).unwrap();
+        assert_eq!(Roman::MAX_FORMATTED_LEN, len);
+        assert_eq!(n.format(Style::Unicode).to_string().as_bytes(), &buf[..]);
+    }
+
+    #[test]
+    fn too_small() {
+        let mut buf = [b'-'; 3];
+        let n = Roman::new(8).unwrap();
+        assert_eq!(
+            Err(BufTooSmall { needed: 4 }),
+            n.write_buf(&mut buf, Style::Upper)
+        );
+        assert_eq!(b"---", &buf);
+        assert_eq!(
+            Ok(3),
+            Roman::new(7).unwrap().write_buf(&mut buf, Style::Upper)
+        );
+        assert_eq!(b"VII", &buf);
+    }
+}
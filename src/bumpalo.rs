@@ -0,0 +1,79 @@
+//! Formatting into a [bumpalo](https://docs.rs/bumpalo) arena.
+//!
+//! When generating a large document, formatting each numeral into its own `String`
+//! means one heap allocation per value, and another deallocation for each when the
+//! document is thrown away. Formatting into a [`Bump`] instead costs a pointer bump
+//! per value, and the whole lot is released at once when the arena is dropped or
+//! reset.
+//!
+//! ```rust
+//! use bumpalo::Bump;
+//! use xvii::{bumpalo::format_all_in, Roman, Style};
+//!
+//! let bump = Bump::new();
+//! let pages = (1..=4).map(|n| Roman::new(n).unwrap());
+//! let labels = format_all_in(pages, Style::Lower, &bump);
+//! assert_eq!(labels, ["i", "ii", "iii", "iv"]);
+//! ```
+
+use crate::{Roman, Style};
+use bumpalo::Bump;
+
+impl Roman {
+    /// Formats a numeral into `bump`, returning a string which lives as long as the
+    /// arena.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use bumpalo::Bump;
+    /// use xvii::{Roman, Style};
+    ///
+    /// let bump = Bump::new();
+    /// let s = Roman::new(1984).unwrap().format_in(Style::Upper, &bump);
+    /// assert_eq!(s, "MCMLXXXIV");
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "bumpalo")))]
+    pub fn format_in(self, style: Style, bump: &Bump) -> &str {
+        self.format(style).with_str(|s| bump.alloc_str(s))
+    }
+}
+
+/// Formats every numeral from `values` into `bump`.
+///
+/// Both the strings and the slice holding them are allocated in the arena.
+pub fn format_all_in<I>(values: I, style: Style, bump: &Bump) -> &[&str]
+where
+    I: IntoIterator<Item = Roman>,
+    I::IntoIter: ExactSizeIterator,
+{
+    bump.alloc_slice_fill_iter(values.into_iter().map(|roman| roman.format_in(style, bump)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::format_all_in;
+    use crate::{Roman, Style};
+    use bumpalo::Bump;
+
+    #[test]
+    fn longest_numeral_fits() {
+        let bump = Bump::new();
+        let s = Roman::new(4888).unwrap().format_in(Style::Upper, &bump);
+        assert_eq!("MMMMDCCCLXXXVIII", s);
+
+        let n = Roman::new(4888).unwrap();
+        let s = n.format_in(Style::Unicode, &bump);
+        assert_eq!(n.format(Style::Unicode).to_string(), s);
+    }
+
+    #[test]
+    fn format_all() {
+        let bump = Bump::new();
+        let values = [9, 40, 1999].iter().map(|&n| Roman::new(n).unwrap());
+        assert_eq!(
+            ["IX", "XL", "MCMXCIX"],
+            format_all_in(values, Style::Upper, &bump)
+        );
+    }
+}
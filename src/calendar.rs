@@ -0,0 +1,587 @@
+//! Dates in the classical Roman calendar.
+//!
+//! The Romans named each day by counting inclusively to the next of three fixed
+//! points in the month: the Kalends (the first), the Nones (the fifth, or the seventh
+//! in March, May, July and October) and the Ides (eight days after the Nones). The
+//! 24th of February is thus *ante diem VI Kalendas Martias*, the sixth day before the
+//! Kalends of March, and the day before any fixed point is *pridie*.
+//!
+//! [`RomanDate`] parses and formats such expressions. Parsing is case-insensitive and
+//! accepts the usual abbreviations: `a.d.`, `ad` or `ante diem`; `prid.` or `pridie`;
+//! `Kal.`, `Non.`, `Id.` or their full forms; and any month name of three letters or
+//! more, with `j` for `i` allowed. A bare numeral without `a.d.` is also accepted.
+//!
+//! ```rust
+//! use xvii::calendar::{Month, Reference, RomanDate};
+//!
+//! let date: RomanDate = "a.d. VI Kal. Mart.".parse().unwrap();
+//! assert_eq!(date.count(), 6);
+//! assert_eq!(date.reference(), Reference::Kalends);
+//! assert_eq!(date.to_month_day(), (Month::February, 24));
+//!
+//! let date: RomanDate = "pridie Idus Martias".parse().unwrap();
+//! assert_eq!(date.to_month_day(), (Month::March, 14));
+//! assert_eq!(date.to_string(), "prid. Id. Mart.");
+//! ```
+//!
+//! Dates are reckoned in a common year; the Julian leap day, a doubled
+//! *a.d. VI Kal. Mart.*, is not represented.
+
+use crate::{Error, Roman};
+use core::{
+    convert::TryFrom,
+    fmt::{self, Display},
+    str::FromStr,
+};
+
+/// A month of the year.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum Month {
+    /// *Ianuarius*
+    January = 1,
+    /// *Februarius*
+    February,
+    /// *Martius*
+    March,
+    /// *Aprilis*
+    April,
+    /// *Maius*
+    May,
+    /// *Iunius*
+    June,
+    /// *Iulius*, formerly *Quintilis*
+    July,
+    /// *Augustus*, formerly *Sextilis*
+    August,
+    /// *September*
+    September,
+    /// *October*
+    October,
+    /// *November*
+    November,
+    /// *December*
+    December,
+}
+
+static MONTHS: [Month; 12] = [
+    Month::January,
+    Month::February,
+    Month::March,
+    Month::April,
+    Month::May,
+    Month::June,
+    Month::July,
+    Month::August,
+    Month::September,
+    Month::October,
+    Month::November,
+    Month::December,
+];
+
+/// Three-letter stems by which month names are recognised, in month order.
+static STEMS: [&str; 12] = [
+    "ian", "feb", "mar", "apr", "mai", "iun", "iul", "aug", "sep", "oct", "nov", "dec",
+];
+
+static ABBREVIATIONS: [&str; 12] = [
+    "Ian.", "Feb.", "Mart.", "Apr.", "Mai.", "Iun.", "Iul.", "Aug.", "Sept.", "Oct.", "Nov.",
+    "Dec.",
+];
+
+impl Month {
+    /// Returns the number of the month, counting January as 1.
+    pub const fn number(self) -> u8 {
+        self as u8
+    }
+
+    /// Returns the month with the given number, counting January as 1.
+    pub fn from_number(number: u8) -> Option<Month> {
+        MONTHS.get(usize::from(number).checked_sub(1)?).copied()
+    }
+
+    /// Returns the number of days in the month in a common year.
+    pub const fn days(self) -> u8 {
+        match self {
+            Month::February => 28,
+            Month::April | Month::June | Month::September | Month::November => 30,
+            _ => 31,
+        }
+    }
+
+    /// Returns the day of the month on which the Nones fall.
+    pub const fn nones(self) -> u8 {
+        match self {
+            Month::March | Month::May | Month::July | Month::October => 7,
+            _ => 5,
+        }
+    }
+
+    /// Returns the day of the month on which the Ides fall.
+    pub const fn ides(self) -> u8 {
+        self.nones() + 8
+    }
+
+    fn previous(self) -> Month {
+        MONTHS[(usize::from(self.number()) + 10) % 12]
+    }
+
+    fn parse_word(word: &str) -> Option<Month> {
+        let bytes = word.as_bytes();
+        if bytes.len() < 3 {
+            return None;
+        }
+
+        let matches = |stem: &str| {
+            stem.bytes().zip(bytes).all(|(s, &b)| {
+                s == b.to_ascii_lowercase() || (s == b'i' && b.eq_ignore_ascii_case(&b'j'))
+            })
+        };
+
+        if let Some(index) = STEMS.iter().position(|stem| matches(stem)) {
+            Some(MONTHS[index])
+        } else if matches("qui") {
+            Some(Month::July)
+        } else if matches("sex") {
+            Some(Month::August)
+        } else {
+            None
+        }
+    }
+}
+
+/// One of the three fixed points of the month from which days were counted.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum Reference {
+    /// The Kalends, the first day of the month.
+    Kalends,
+    /// The Nones, the fifth or seventh day of the month.
+    Nones,
+    /// The Ides, the thirteenth or fifteenth day of the month.
+    Ides,
+}
+
+impl Reference {
+    fn parse_word(word: &str) -> Option<Reference> {
+        let starts_with = |prefix: &str| matches!(word.get(..prefix.len()), Some(head) if head.eq_ignore_ascii_case(prefix));
+
+        if starts_with("kal") || starts_with("cal") {
+            Some(Reference::Kalends)
+        } else if starts_with("non") {
+            Some(Reference::Nones)
+        } else if word.eq_ignore_ascii_case("id") || starts_with("idu") || starts_with("idi") {
+            Some(Reference::Ides)
+        } else {
+            None
+        }
+    }
+
+    const fn abbreviation(self) -> &'static str {
+        match self {
+            Reference::Kalends => "Kal.",
+            Reference::Nones => "Non.",
+            Reference::Ides => "Id.",
+        }
+    }
+}
+
+/// An error in parsing or constructing a [`RomanDate`].
+///
+/// Positions are byte offsets into the parsed string.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum CalendarError {
+    /// The day count beginning at the given position is not a valid numeral.
+    Numeral(usize, Error),
+
+    /// The day count does not fall between the given reference and the one before
+    /// it. Holds the count and the largest count allowed.
+    InvalidDayCount(u16, u16),
+
+    /// The word at the given position is not part of a date.
+    UnexpectedWord(usize),
+
+    /// No Kalends, Nones or Ides was given.
+    MissingReference,
+
+    /// No month was given.
+    MissingMonth,
+
+    /// No year was given.
+    MissingYear,
+
+    /// The decimal number beginning at the given position is not valid.
+    InvalidNumber(usize),
+
+    /// The month numeral is not between I and XII.
+    InvalidMonth(u16),
+
+    /// The day does not exist in the given month.
+    InvalidDay(u16),
+}
+
+impl Display for CalendarError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CalendarError::Numeral(pos, e) => write!(f, "Invalid day count at {}: {}", pos, e),
+            CalendarError::InvalidDayCount(count, max) => {
+                write!(f, "Day count out of range: {} (at most {})", count, max)
+            }
+            CalendarError::UnexpectedWord(pos) => write!(f, "Unexpected word at {}", pos),
+            CalendarError::MissingReference => f.write_str("Expected Kalends, Nones or Ides"),
+            CalendarError::MissingMonth => f.write_str("Expected a month"),
+            CalendarError::MissingYear => f.write_str("Expected a year"),
+            CalendarError::InvalidNumber(pos) => write!(f, "Invalid number at {}", pos),
+            CalendarError::InvalidMonth(month) => write!(f, "Month out of range: {}", month),
+            CalendarError::InvalidDay(day) => write!(f, "Day out of range: {}", day),
+        }
+    }
+}
+
+impl core::error::Error for CalendarError {}
+
+/// A date expressed as a count of days before the Kalends, Nones or Ides of a month.
+///
+/// A count of 1 is the reference day itself, and 2 is the day before (*pridie*).
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct RomanDate {
+    count: u8,
+    reference: Reference,
+    month: Month,
+}
+
+impl RomanDate {
+    /// Creates a date from its day count, reference and month.
+    ///
+    /// Fails with [`CalendarError::InvalidDayCount`] if counting back `count` days
+    /// from the reference passes the previous reference.
+    pub fn new(count: u16, reference: Reference, month: Month) -> Result<Self, CalendarError> {
+        let max = u16::from(match reference {
+            Reference::Kalends => {
+                let previous = month.previous();
+                previous.days() + 1 - previous.ides()
+            }
+            Reference::Nones => month.nones() - 1,
+            Reference::Ides => month.ides() - month.nones(),
+        });
+
+        if (1..=max).contains(&count) {
+            Ok(RomanDate {
+                count: count as u8,
+                reference,
+                month,
+            })
+        } else {
+            Err(CalendarError::InvalidDayCount(count, max))
+        }
+    }
+
+    /// Returns the inclusive count of days to the reference.
+    pub const fn count(&self) -> u8 {
+        self.count
+    }
+
+    /// Returns the fixed point the date is counted from.
+    pub const fn reference(&self) -> Reference {
+        self.reference
+    }
+
+    /// Returns the month named in the date.
+    ///
+    /// Days counted back from the Kalends fall in the month before this one.
+    pub const fn month(&self) -> Month {
+        self.month
+    }
+
+    /// Converts the date into a month and day of the month.
+    pub fn to_month_day(&self) -> (Month, u8) {
+        let back = self.count - 1;
+        match self.reference {
+            Reference::Kalends if back == 0 => (self.month, 1),
+            Reference::Kalends => {
+                let previous = self.month.previous();
+                (previous, previous.days() + 1 - back)
+            }
+            Reference::Nones => (self.month, self.month.nones() - back),
+            Reference::Ides => (self.month, self.month.ides() - back),
+        }
+    }
+}
+
+impl Display for RomanDate {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.count {
+            1 => {}
+            2 => f.write_str("prid. ")?,
+            // `new` holds every count to at most 19, so the numeral is never clamped.
+            n => write!(f, "a.d. {} ", Roman::new_saturating(n.into()))?,
+        }
+
+        write!(
+            f,
+            "{} {}",
+            self.reference.abbreviation(),
+            ABBREVIATIONS[usize::from(self.month.number()) - 1]
+        )
+    }
+}
+
+impl FromStr for RomanDate {
+    type Err = CalendarError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut words = Words { s, pos: 0 }.peekable();
+        let is = |word: &str, expected: &str| word.eq_ignore_ascii_case(expected);
+
+        let count = match words.peek().copied() {
+            Some((_, word)) if Reference::parse_word(word).is_some() => 1,
+            Some((_, word)) if is(word, "prid") || is(word, "pridie") => {
+                words.next();
+                2
+            }
+            Some((_, word)) => {
+                if is(word, "a") || is(word, "ante") {
+                    words.next();
+                    match words.next() {
+                        Some((_, word)) if is(word, "d") || is(word, "diem") => {}
+                        Some((pos, _)) => return Err(CalendarError::UnexpectedWord(pos)),
+                        None => return Err(CalendarError::MissingReference),
+                    }
+                } else if is(word, "ad") {
+                    words.next();
+                }
+
+                let (pos, numeral) = words.next().ok_or(CalendarError::MissingReference)?;
+                numeral
+                    .parse::<Roman>()
+                    .map_err(|e| CalendarError::Numeral(pos, e))?
+                    .value()
+            }
+            None => return Err(CalendarError::MissingReference),
+        };
+
+        let reference = match words.next() {
+            Some((pos, word)) => {
+                Reference::parse_word(word).ok_or(CalendarError::UnexpectedWord(pos))?
+            }
+            None => return Err(CalendarError::MissingReference),
+        };
+
+        let month = match words.next() {
+            Some((pos, word)) => {
+                Month::parse_word(word).ok_or(CalendarError::UnexpectedWord(pos))?
+            }
+            None => return Err(CalendarError::MissingMonth),
+        };
+
+        if let Some((pos, _)) = words.next() {
+            return Err(CalendarError::UnexpectedWord(pos));
+        }
+
+        RomanDate::new(count, reference, month)
+    }
+}
+
+/// Parses a date written with a Roman numeral for the month, e.g. `17.XI.2023`.
+///
+/// The parts may be separated by periods or hyphens, but the same separator must be
+/// used throughout. The month numeral may be in either case, and the day is checked
+/// against the length of the month, including February in Gregorian leap years.
+/// Returns the day, month and year.
+///
+/// ## Examples
+///
+/// ```
+/// use xvii::calendar::{parse_dotted_date, CalendarError, Month};
+///
+/// assert_eq!(parse_dotted_date("17.XI.2023"), Ok((17, Month::November, 2023)));
+/// assert_eq!(parse_dotted_date("29-ii-2024"), Ok((29, Month::February, 2024)));
+/// assert_eq!(parse_dotted_date("1.XIII.2023"), Err(CalendarError::InvalidMonth(13)));
+/// assert_eq!(parse_dotted_date("29.II.2023"), Err(CalendarError::InvalidDay(29)));
+/// ```
+pub fn parse_dotted_date(s: &str) -> Result<(u8, Month, u16), CalendarError> {
+    let separator = match s.find(['.', '-']) {
+        Some(idx) => &s[idx..=idx],
+        None if s.is_empty() => return Err(CalendarError::InvalidNumber(0)),
+        None => return Err(CalendarError::MissingMonth),
+    };
+
+    let mut offset = 0;
+    let mut parts = s.split(separator).map(|part| {
+        let start = offset;
+        offset += part.len() + 1;
+        (start, part)
+    });
+
+    let (pos, day) = parts.next().ok_or(CalendarError::InvalidNumber(0))?;
+    let day = parse_decimal(day, pos)?;
+
+    let (pos, month) = parts.next().ok_or(CalendarError::MissingMonth)?;
+    let month = month
+        .parse::<Roman>()
+        .map_err(|e| CalendarError::Numeral(pos, e))?
+        .value();
+    let month = u8::try_from(month)
+        .ok()
+        .and_then(Month::from_number)
+        .ok_or(CalendarError::InvalidMonth(month))?;
+
+    let (pos, year) = parts.next().ok_or(CalendarError::MissingYear)?;
+    let year = parse_decimal(year, pos)?;
+
+    if let Some((pos, _)) = parts.next() {
+        return Err(CalendarError::UnexpectedWord(pos));
+    }
+
+    let leap = year % 4 == 0 && (year % 100 != 0 || year % 400 == 0);
+    let days = match month {
+        Month::February if leap => 29,
+        _ => month.days(),
+    };
+
+    match u8::try_from(day) {
+        Ok(day) if (1..=days).contains(&day) => Ok((day, month, year)),
+        _ => Err(CalendarError::InvalidDay(day)),
+    }
+}
+
+fn parse_decimal(s: &str, pos: usize) -> Result<u16, CalendarError> {
+    if s.is_empty() || !s.bytes().all(|u| u.is_ascii_digit()) {
+        return Err(CalendarError::InvalidNumber(pos));
+    }
+    s.parse().map_err(|_| CalendarError::InvalidNumber(pos))
+}
+
+/// Iterates the alphanumeric words of a string, with their byte offsets.
+///
+/// Periods, whitespace and any other punctuation separate words.
+struct Words<'a> {
+    s: &'a str,
+    pos: usize,
+}
+
+impl<'a> Iterator for Words<'a> {
+    type Item = (usize, &'a str);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let rest = &self.s[self.pos..];
+        let start = self.pos + rest.find(|c: char| c.is_alphanumeric())?;
+
+        let rest = &self.s[start..];
+        let len = rest
+            .find(|c: char| !c.is_alphanumeric())
+            .unwrap_or(rest.len());
+        self.pos = start + len;
+
+        Some((start, &rest[..len]))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CalendarError, Month, Reference, RomanDate};
+    use crate::Error;
+
+    fn parse(s: &str) -> Result<(Month, u8), CalendarError> {
+        s.parse::<RomanDate>().map(|date| date.to_month_day())
+    }
+
+    #[test]
+    fn abbreviation_variants() {
+        assert_eq!(Ok((Month::February, 24)), parse("a.d. VI Kal. Mart."));
+        assert_eq!(Ok((Month::February, 24)), parse("a. d. vi kal mar"));
+        assert_eq!(
+            Ok((Month::February, 24)),
+            parse("ante diem VI Kalendas Martias")
+        );
+        assert_eq!(Ok((Month::February, 24)), parse("ad VI Kal. Mart."));
+        assert_eq!(Ok((Month::February, 24)), parse("VI Kal. Mart."));
+        assert_eq!(Ok((Month::July, 15)), parse("Idibus Quintilibus"));
+        assert_eq!(Ok((Month::January, 5)), parse("Non. Jan."));
+    }
+
+    #[test]
+    fn kalends_of_january_count_into_december() {
+        assert_eq!(Ok((Month::December, 31)), parse("prid. Kal. Ian."));
+        assert_eq!(Ok((Month::December, 14)), parse("a.d. XIX Kal. Ian."));
+        assert_eq!(Ok((Month::January, 1)), parse("Kal. Ian."));
+    }
+
+    #[test]
+    fn malformed_day_counts() {
+        assert_eq!(
+            Err(CalendarError::InvalidDayCount(5, 4)),
+            parse("a.d. V Non. Ian.")
+        );
+        assert_eq!(
+            Err(CalendarError::InvalidDayCount(17, 16)),
+            parse("a.d. XVII Kal. Mart.")
+        );
+        assert_eq!(
+            Err(CalendarError::Numeral(5, Error::InvalidDigit(b'Q', 0))),
+            parse("a.d. Q Kal. Mart.")
+        );
+    }
+
+    #[test]
+    fn missing_and_unexpected_words() {
+        assert_eq!(Err(CalendarError::MissingMonth), parse("a.d. III Id."));
+        assert_eq!(Err(CalendarError::MissingReference), parse("a.d. III"));
+        assert_eq!(
+            Err(CalendarError::UnexpectedWord(9)),
+            parse("a.d. III Foo Mart.")
+        );
+        assert_eq!(
+            Err(CalendarError::UnexpectedWord(11)),
+            parse("Kal. Mart. MMXXIV")
+        );
+    }
+
+    #[test]
+    fn dotted_dates() {
+        use super::parse_dotted_date;
+
+        assert_eq!(
+            Ok((1, Month::January, 1900)),
+            parse_dotted_date("01.i.1900")
+        );
+        assert_eq!(
+            Err(CalendarError::InvalidDay(29)),
+            parse_dotted_date("29.II.1900")
+        );
+        assert_eq!(
+            Err(CalendarError::Numeral(3, Error::InvalidDigit(b'.', 2))),
+            parse_dotted_date("17-XI.2023")
+        );
+        assert_eq!(
+            Err(CalendarError::Numeral(3, Error::InvalidDigit(b'Q', 0))),
+            parse_dotted_date("17.Q.2023")
+        );
+        assert_eq!(Err(CalendarError::MissingYear), parse_dotted_date("17.XI"));
+        assert_eq!(
+            Err(CalendarError::UnexpectedWord(11)),
+            parse_dotted_date("17.XI.2023.")
+        );
+    }
+
+    #[test]
+    fn formats_canonically() {
+        let date = RomanDate::new(3, Reference::Nones, Month::October).unwrap();
+        assert_eq!("a.d. III Non. Oct.", date.to_string());
+        assert_eq!(Ok(date), date.to_string().parse());
+        assert_eq!((Month::October, 5), date.to_month_day());
+    }
+
+    #[test]
+    fn every_date_formats() {
+        for month in (1..=12).filter_map(Month::from_number) {
+            for &reference in &[Reference::Kalends, Reference::Nones, Reference::Ides] {
+                for count in 1.. {
+                    let date = match RomanDate::new(count, reference, month) {
+                        Ok(date) => date,
+                        Err(_) => break,
+                    };
+                    assert_eq!(Ok(date), date.to_string().parse());
+                }
+            }
+        }
+    }
+}
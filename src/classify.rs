@@ -0,0 +1,241 @@
+use crate::{roman::ladder, unit, Result, Roman};
+use core::{convert::TryFrom, iter};
+
+/// The orthographic form in which a numeral was written.
+///
+/// Returned by [`classify`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum NumeralForm {
+    /// Standard subtractive notation, exactly as this crate formats it. E.g.: `XIV`.
+    Canonical,
+    /// Purely additive notation, with no subtractive pairs at all. E.g.: `XIIII`.
+    Additive,
+    /// Clock-face notation: subtractive everywhere except for a trailing `IIII`.
+    /// E.g.: `XLIIII`.
+    Clock,
+    /// Medieval notation, in which the final `i` is written as `j`. E.g.: `xiij`.
+    Medieval,
+    /// Subtractive notation using pairs outside the classical six.
+    /// E.g.: `IC` or `IIX`.
+    ExtendedSubtractive,
+}
+
+/// Reports the form in which a numeral string was written.
+///
+/// The string must be a valid numeral in its own right; a string which cannot be
+/// parsed produces the same error as parsing it would. Case is ignored, and each
+/// precomposed Unicode numeral is read as the digits it stands for, so that `Ⅻ` is
+/// canonical like `XII` and `ⅠⅠⅠⅠ` is additive. Where a string fits more than one
+/// form (`IIII` is additive *and* clock-style), the first of canonical, additive,
+/// clock-style and extended-subtractive is reported.
+///
+/// ## Examples
+///
+/// ```
+/// use xvii::{classify, NumeralForm};
+///
+/// assert_eq!(classify("XIV").unwrap(), NumeralForm::Canonical);
+/// assert_eq!(classify("XIIII").unwrap(), NumeralForm::Additive);
+/// assert_eq!(classify("XLIIII").unwrap(), NumeralForm::Clock);
+/// assert_eq!(classify("xiij").unwrap(), NumeralForm::Medieval);
+/// assert_eq!(classify("IC").unwrap(), NumeralForm::ExtendedSubtractive);
+/// ```
+pub fn classify(s: &str) -> Result<NumeralForm> {
+    if let Some(head) = s.strip_suffix(|c| c == 'j' || c == 'J') {
+        // The terminal j is just a final i, and a trailing i can never take part in a
+        // subtractive pair, so the value is that of everything before it plus one.
+        if !head.is_empty() {
+            Roman::new(head.parse::<Roman>()?.value() + 1)?;
+        }
+        return Ok(NumeralForm::Medieval);
+    }
+
+    let value = s.parse::<Roman>()?.value();
+
+    if matches_ladder(s, value, false) {
+        Ok(NumeralForm::Canonical)
+    } else if is_additive(s) {
+        Ok(NumeralForm::Additive)
+    } else if matches_ladder(s, value, true) {
+        Ok(NumeralForm::Clock)
+    } else {
+        Ok(NumeralForm::ExtendedSubtractive)
+    }
+}
+
+/// Tests whether `s` is a numeral which `FromStr` would accept.
+///
+/// This is the same check as parsing, without building a [`Roman`] or an error, so
+/// precomposed Unicode numerals such as `Ⅻ` are valid.
+///
+/// ## Examples
+///
+/// ```
+/// assert!(xvii::is_valid("MCMLXXXIV"));
+/// assert!(xvii::is_valid("iiii"));
+/// assert!(!xvii::is_valid("MMMMM"));
+/// assert!(!xvii::is_valid(""));
+/// ```
+pub fn is_valid(s: &str) -> bool {
+    matches!(unit::sum(s), Ok(1..=4999))
+}
+
+/// Tests whether `s` is a numeral written exactly as this crate formats it, ignoring
+/// case.
+///
+/// The check is a single pass over the bytes of `s`, without parsing it first. Only
+/// ASCII digits are canonical; [`classify`] reads precomposed Unicode numerals too.
+///
+/// ## Examples
+///
+/// ```
+/// assert!(xvii::is_canonical("MCMLXXXIV"));
+/// assert!(xvii::is_canonical("xiv"));
+/// assert!(!xvii::is_canonical("XIIII"));
+/// assert!(!xvii::is_canonical("IC"));
+/// ```
+pub fn is_canonical(s: &str) -> bool {
+    let bytes = s.as_bytes();
+    let mut pos = bytes
+        .iter()
+        .take(4)
+        .take_while(|b| b.eq_ignore_ascii_case(&b'M'))
+        .count();
+
+    for place in &PLACES {
+        pos += place_len(&bytes[pos..], place);
+    }
+
+    pos > 0 && pos == bytes.len()
+}
+
+/// The digits for one, five and ten in each decimal place below the thousands.
+const PLACES: [[u8; 3]; 3] = [*b"CDM", *b"XLC", *b"IVX"];
+
+/// Returns the length of the canonical spelling of a single decimal place at the
+/// start of `s`, which is zero if the place is empty.
+fn place_len(s: &[u8], &[one, five, ten]: &[u8; 3]) -> usize {
+    let is = |i: usize, d: u8| s.get(i).is_some_and(|b| b.eq_ignore_ascii_case(&d));
+
+    if is(0, one) && (is(1, five) || is(1, ten)) {
+        return 2;
+    }
+
+    let start = usize::from(is(0, five));
+    let mut len = start;
+    while len < start + 3 && is(len, one) {
+        len += 1;
+    }
+    len
+}
+
+/// Returns the values of the digits of a numeral which has already been parsed,
+/// reading each precomposed Unicode numeral as the digits it stands for.
+fn digits(s: &str) -> impl Iterator<Item = u16> + '_ {
+    s.chars().flat_map(|c| {
+        let ascii = u8::try_from(c).ok().and_then(unit::to_digit);
+        let form = unit::number_form(c)
+            .map(|(&first, rest)| iter::once(first).chain(rest.iter().copied()));
+        ascii.into_iter().chain(form.into_iter().flatten())
+    })
+}
+
+/// Tests whether `s` spells `value` exactly as the formatting ladder would.
+///
+/// With `clock` set, a final `IV` is expected as `IIII` instead.
+fn matches_ladder(s: &str, mut value: u16, clock: bool) -> bool {
+    let mut rest = digits(s);
+
+    for entry in ladder::VALUES {
+        while value >= entry.value {
+            let expected = if clock && entry.value == 4 {
+                "IIII"
+            } else {
+                entry.upper
+            };
+
+            if !expected.bytes().all(|b| rest.next() == unit::to_digit(b)) {
+                return false;
+            }
+
+            value -= entry.value;
+        }
+    }
+
+    rest.next().is_none()
+}
+
+/// Tests whether no digit in `s` is followed by a digit of greater value.
+fn is_additive(s: &str) -> bool {
+    let mut previous = u16::MAX;
+    digits(s).all(|digit| digit <= core::mem::replace(&mut previous, digit))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{classify, is_canonical, is_valid, NumeralForm};
+    use crate::{Error, Roman};
+
+    #[test]
+    fn canonical_ignores_case() {
+        assert_eq!(Ok(NumeralForm::Canonical), classify("mcmlxxxiv"));
+        assert_eq!(Ok(NumeralForm::Canonical), classify("MMMMCMXCIX"));
+    }
+
+    #[test]
+    fn iiii_is_additive() {
+        assert_eq!(Ok(NumeralForm::Additive), classify("IIII"));
+        assert_eq!(Ok(NumeralForm::Additive), classify("MDCCCCLXXXIIII"));
+    }
+
+    #[test]
+    fn clock_requires_other_subtraction() {
+        assert_eq!(Ok(NumeralForm::Clock), classify("MCMXCIIII"));
+    }
+
+    #[test]
+    fn medieval_terminal_j() {
+        assert_eq!(Ok(NumeralForm::Medieval), classify("j"));
+        assert_eq!(Ok(NumeralForm::Medieval), classify("vij"));
+        assert_eq!(Err(Error::InvalidDigit(b'q', 0)), classify("qij"));
+    }
+
+    #[test]
+    fn extended_subtractive() {
+        assert_eq!(Ok(NumeralForm::ExtendedSubtractive), classify("XM"));
+        assert_eq!(Ok(NumeralForm::ExtendedSubtractive), classify("IIX"));
+    }
+
+    #[test]
+    fn canonical_predicate_matches_formatting() {
+        for n in 1..=4999 {
+            let formatted = Roman::new(n).unwrap().to_string();
+            assert!(is_canonical(&formatted), "{}", formatted);
+            assert!(is_valid(&formatted));
+        }
+        for s in &[
+            "", "MMMMM", "IIII", "CMD", "IXI", "VX", "XIIX", "LL", "IM", " I",
+        ] {
+            assert!(!is_canonical(s), "{}", s);
+        }
+        assert!(is_valid("IM"));
+        assert!(!is_valid("XQ"));
+    }
+
+    #[test]
+    fn number_forms() {
+        assert_eq!(Ok(NumeralForm::Canonical), classify("Ⅻ"));
+        assert_eq!(Ok(NumeralForm::Canonical), classify("ⅯⅭⅯⅬⅩⅩⅩⅣ"));
+        assert_eq!(Ok(NumeralForm::Additive), classify("ⅰⅰⅰⅰ"));
+        assert_eq!(Ok(NumeralForm::Clock), classify("ⅩⅬⅠⅠⅠⅠ"));
+        assert_eq!(Ok(NumeralForm::ExtendedSubtractive), classify("ⅠⅭ"));
+        assert!(is_valid("Ⅻ"));
+        assert!(!is_canonical("Ⅻ"));
+    }
+
+    #[test]
+    fn invalid_input_is_an_error() {
+        assert_eq!(Err(Error::InvalidDigit(b'Q', 1)), classify("XQI"));
+        assert_eq!(Err(Error::OutOfRange(0)), classify(""));
+    }
+}
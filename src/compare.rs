@@ -0,0 +1,123 @@
+use crate::{unit, Result, Roman, Style};
+use core::cmp::Ordering;
+
+/// Compares two numeral strings by value.
+///
+/// Each string is read once, from left to right, exactly as it would be by
+/// parsing it; either string failing to parse is an error. This makes it
+/// convenient to sort string data by numeral value directly.
+///
+/// ## Examples
+///
+/// ```
+/// use std::cmp::Ordering;
+///
+/// assert_eq!(xvii::cmp_str("IX", "xi").unwrap(), Ordering::Less);
+/// assert_eq!(xvii::cmp_str("IIII", "IV").unwrap(), Ordering::Equal);
+///
+/// let mut chapters = vec!["XII", "IV", "IX", "I"];
+/// chapters.sort_by(|a, b| xvii::cmp_str(a, b).unwrap());
+/// assert_eq!(chapters, ["I", "IV", "IX", "XII"]);
+/// ```
+pub fn cmp_str(a: &str, b: &str) -> Result<Ordering> {
+    Ok(value(a)?.cmp(&value(b)?))
+}
+
+fn value(s: &str) -> Result<u16> {
+    Roman::new(unit::sum(s)?).map(Roman::value)
+}
+
+impl PartialEq<u16> for Roman {
+    fn eq(&self, other: &u16) -> bool {
+        self.value() == *other
+    }
+}
+
+impl PartialEq<Roman> for u16 {
+    fn eq(&self, other: &Roman) -> bool {
+        *self == other.value()
+    }
+}
+
+// A numeral equals a string holding its canonical form, in either case, as
+// `is_canonical` would accept it. `IIII` is not equal to 4.
+impl PartialEq<str> for Roman {
+    fn eq(&self, other: &str) -> bool {
+        let (buf, len) = self.to_array(Style::Upper);
+        buf[..len].eq_ignore_ascii_case(other.as_bytes())
+    }
+}
+
+impl PartialEq<&str> for Roman {
+    fn eq(&self, other: &&str) -> bool {
+        *self == **other
+    }
+}
+
+impl PartialEq<Roman> for str {
+    fn eq(&self, other: &Roman) -> bool {
+        *other == *self
+    }
+}
+
+impl PartialEq<Roman> for &str {
+    fn eq(&self, other: &Roman) -> bool {
+        *other == **self
+    }
+}
+
+impl PartialOrd<u16> for Roman {
+    fn partial_cmp(&self, other: &u16) -> Option<Ordering> {
+        self.value().partial_cmp(other)
+    }
+}
+
+impl PartialOrd<Roman> for u16 {
+    fn partial_cmp(&self, other: &Roman) -> Option<Ordering> {
+        self.partial_cmp(&other.value())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::cmp_str;
+    use crate::{Error, Roman};
+    use core::cmp::Ordering;
+
+    #[test]
+    fn compares_by_value_not_text() {
+        assert_eq!(Ok(Ordering::Greater), cmp_str("C", "LXXXVIII"));
+        assert_eq!(Ok(Ordering::Less), cmp_str("MCMXCIX", "MM"));
+    }
+
+    #[test]
+    fn either_side_may_fail() {
+        assert_eq!(Err(Error::InvalidDigit(b'Q', 0)), cmp_str("Q", "I"));
+        assert_eq!(Err(Error::OutOfRange(0)), cmp_str("I", ""));
+    }
+
+    #[test]
+    fn compares_with_integers() {
+        let n = Roman::new(42).unwrap();
+        assert!(n == 42);
+        assert!(42 == n);
+        assert!(n != 43);
+        assert!(n < 100);
+        assert!(1 < n);
+        assert_eq!(Some(Ordering::Equal), n.partial_cmp(&42));
+        assert_eq!(Some(Ordering::Greater), 100.partial_cmp(&n));
+    }
+
+    #[test]
+    fn compares_with_strings() {
+        let n = Roman::new(17).unwrap();
+        assert!(n == "XVII");
+        assert!(n == "xvii");
+        assert!("XvIi" == n);
+        assert!(n != "XVI");
+        assert!(n != "XVIII");
+        assert!(Roman::new(4).unwrap() != "IIII");
+        assert!(n == *"XVII");
+        assert!(*"xvii" == n);
+    }
+}
@@ -0,0 +1,88 @@
+//! Export of the `xvii:numerals/roman` interface as a WebAssembly component.
+//!
+//! The interface is defined in `wit/xvii.wit`. To build a component, compile the crate
+//! as a `cdylib` for a WASI preview 2 target:
+//!
+//! ```text
+//! cargo rustc --release --features component --target wasm32-wasip2 --crate-type cdylib
+//! ```
+
+use crate::{Error, Roman};
+use bindings::exports::xvii::numerals::roman::{self, Guest};
+use core::convert::TryFrom;
+
+#[allow(unsafe_code)]
+mod bindings {
+    wit_bindgen::generate!({
+        world: "numerals",
+        path: "wit",
+        additional_derives: [PartialEq, Eq],
+    });
+
+    use super::Component;
+    export!(Component);
+}
+
+struct Component;
+
+impl Guest for Component {
+    fn parse(numeral: String) -> Result<u16, roman::Error> {
+        numeral
+            .parse::<Roman>()
+            .map(Roman::value)
+            .map_err(Into::into)
+    }
+
+    fn format(value: u16, style: roman::Style) -> Result<String, roman::Error> {
+        let style = match style {
+            roman::Style::Lower => crate::Style::Lower,
+            roman::Style::Upper => crate::Style::Upper,
+        };
+        Ok(Roman::new(value)?.format(style).to_string())
+    }
+}
+
+impl From<Error> for roman::Error {
+    fn from(e: Error) -> Self {
+        match e {
+            Error::InvalidDigit(digit, offset) => {
+                roman::Error::InvalidDigit((digit, u32::try_from(offset).unwrap_or(u32::MAX)))
+            }
+            Error::InvalidChar(c, offset) => {
+                roman::Error::InvalidChar((c, u32::try_from(offset).unwrap_or(u32::MAX)))
+            }
+            Error::OutOfRange(value) => roman::Error::OutOfRange(value),
+            Error::Zero => roman::Error::Zero,
+            Error::Overflow => roman::Error::Overflow,
+            Error::TooManyRepeats(c) => roman::Error::TooManyRepeats(c),
+            Error::InvalidPair(smaller, larger) => roman::Error::InvalidPair((smaller, larger)),
+            Error::NonCanonical { value, .. } => roman::Error::NonCanonical(value),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{roman, Component, Guest};
+
+    #[test]
+    fn parse() {
+        assert_eq!(Ok(17), Component::parse("xvii".into()));
+        assert_eq!(
+            Err(roman::Error::InvalidDigit((b'Q', 1))),
+            Component::parse("XQ".into())
+        );
+    }
+
+    #[test]
+    fn format() {
+        assert_eq!(
+            Ok(String::from("xlii")),
+            Component::format(42, roman::Style::Lower)
+        );
+        assert_eq!(
+            Err(roman::Error::OutOfRange(5000)),
+            Component::format(5000, roman::Style::Upper)
+        );
+    }
+}
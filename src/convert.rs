@@ -0,0 +1,170 @@
+use crate::{Error, Result, Roman};
+use core::convert::TryFrom;
+
+macro_rules! from_roman {
+    ($($t:ty),*) => {$(
+        impl From<Roman> for $t {
+            fn from(n: Roman) -> $t {
+                <$t>::from(n.value())
+            }
+        }
+    )*};
+}
+
+from_roman!(u16, u32, u64, usize, i32, i64);
+
+impl TryFrom<u16> for Roman {
+    type Error = Error;
+
+    /// Creates a numeral as [`Roman::new`] does.
+    fn try_from(n: u16) -> Result<Self> {
+        Roman::new(n)
+    }
+}
+
+macro_rules! try_from_int {
+    ($($t:ty),*) => {$(
+        impl TryFrom<$t> for Roman {
+            type Error = Error;
+
+            /// Creates a numeral as [`Roman::new`] does, failing with
+            /// [`Error::Overflow`] for values which do not fit a `u16`, negative ones
+            /// included.
+            fn try_from(n: $t) -> Result<Self> {
+                n.to_roman()
+            }
+        }
+    )*};
+}
+
+try_from_int!(u32, u64, usize, i32);
+
+/// Conversions from integers to numerals, as methods on the integers themselves.
+///
+/// ## Examples
+///
+/// ```
+/// use xvii::{Error, ToRoman};
+///
+/// assert_eq!(1984_u16.to_roman()?.to_string(), "MCMLXXXIV");
+/// assert_eq!(0_u16.to_roman(), Err(Error::OutOfRange(0)));
+/// assert_eq!(17.to_roman_lossy().to_string(), "XVII");
+/// assert_eq!(9000.to_roman_lossy().value(), 4999);
+/// # Ok::<(), Error>(())
+/// ```
+pub trait ToRoman {
+    /// Converts `self` to a numeral, failing as [`Roman::new`] does for values out of
+    /// range, or with [`Error::Overflow`] for values which do not fit a `u16`,
+    /// negative ones included.
+    fn to_roman(self) -> Result<Roman>;
+
+    /// Converts `self` to a numeral, clamping values out of range to the nearest
+    /// one in range, `I` or `MMMMCMXCIX`.
+    fn to_roman_lossy(self) -> Roman;
+}
+
+macro_rules! to_roman {
+    ($($t:ty),*) => {$(
+        impl ToRoman for $t {
+            fn to_roman(self) -> Result<Roman> {
+                u16::try_from(self)
+                    .map_err(|_| Error::Overflow)
+                    .and_then(Roman::new)
+            }
+
+            fn to_roman_lossy(self) -> Roman {
+                match self.to_roman() {
+                    Ok(n) => n,
+                    Err(_) if self < 1 => Roman::MIN,
+                    Err(_) => Roman::MAX,
+                }
+            }
+        }
+    )*};
+}
+
+to_roman!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+/// Parsing numerals as methods on strings, as an alternative to `parse::<Roman>()`.
+///
+/// ## Examples
+///
+/// ```
+/// use xvii::{Error, RomanStr};
+///
+/// assert_eq!("XVII".roman()?.value(), 17);
+/// assert_eq!("mcmlxxxiv".roman_value()?, 1984);
+/// assert_eq!("XQ".roman_value(), Err(Error::InvalidDigit(b'Q', 1)));
+/// # Ok::<(), Error>(())
+/// ```
+pub trait RomanStr {
+    /// Parses `self` as a numeral, as `FromStr` does.
+    fn roman(&self) -> Result<Roman>;
+
+    /// Parses `self` as a numeral, as `FromStr` does, returning its value.
+    fn roman_value(&self) -> Result<u16>;
+}
+
+impl RomanStr for str {
+    fn roman(&self) -> Result<Roman> {
+        self.parse()
+    }
+
+    fn roman_value(&self) -> Result<u16> {
+        self.roman().map(Roman::value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Error, Roman, RomanStr, ToRoman};
+    use core::convert::TryFrom;
+
+    #[test]
+    fn into_integers() {
+        let n = Roman::new(1984).unwrap();
+        assert_eq!(1984, u16::from(n));
+        assert_eq!(1985, u32::from(n) + 1);
+        assert_eq!(1984, u64::from(n));
+        assert_eq!(1984, usize::from(n));
+        assert_eq!(-16, 1968 - i32::from(n));
+        assert_eq!(1984, i64::from(n));
+    }
+
+    #[test]
+    fn from_integers() {
+        let n = Roman::new(1984).unwrap();
+        assert_eq!(Ok(n), Roman::try_from(1984_u16));
+        assert_eq!(Ok(n), Roman::try_from(1984_u32));
+        assert_eq!(Ok(n), Roman::try_from(1984_u64));
+        assert_eq!(Ok(n), Roman::try_from(1984_usize));
+        assert_eq!(Ok(n), Roman::try_from(1984_i32));
+
+        assert_eq!(Err(Error::OutOfRange(0)), Roman::try_from(0_u16));
+        assert_eq!(Err(Error::OutOfRange(5000)), Roman::try_from(5000_u32));
+        assert_eq!(Err(Error::Overflow), Roman::try_from(70_000_u64));
+        assert_eq!(Err(Error::Overflow), Roman::try_from(-1_i32));
+    }
+
+    #[test]
+    fn to_roman() {
+        assert_eq!(Ok(Roman::new(17).unwrap()), 17_u8.to_roman());
+        assert_eq!(Ok(Roman::new(4999).unwrap()), 4999_i64.to_roman());
+        assert_eq!(Err(Error::OutOfRange(5000)), 5000_u32.to_roman());
+        assert_eq!(Err(Error::Overflow), (-17_i16).to_roman());
+        assert_eq!(Err(Error::Overflow), u128::MAX.to_roman());
+
+        assert_eq!(Roman::MIN, 0_u8.to_roman_lossy());
+        assert_eq!(Roman::MIN, i64::MIN.to_roman_lossy());
+        assert_eq!(Roman::MAX, 5000_u16.to_roman_lossy());
+        assert_eq!(Roman::MAX, usize::MAX.to_roman_lossy());
+    }
+
+    #[test]
+    fn roman_str() {
+        assert_eq!(Ok(Roman::new(17).unwrap()), "xvii".roman());
+        assert_eq!(Ok(12), "Ⅻ".roman_value());
+        assert_eq!(Ok(17), String::from("XVII").roman_value());
+        assert_eq!(Err(Error::OutOfRange(0)), "".roman());
+    }
+}
@@ -0,0 +1,84 @@
+use crate::{GenericRoman, Roman};
+use core::iter::FusedIterator;
+
+impl Roman {
+    /// Returns the numeral after this one, wrapping from `max` back to `I`.
+    ///
+    /// Useful for positions on a clock face or counters which start over. A value
+    /// already greater than `max` also wraps to `I`.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use xvii::Roman;
+    ///
+    /// let xii = Roman::new(12).unwrap();
+    /// assert_eq!(Roman::new(11).unwrap().cycling_next(xii).value(), 12);
+    /// assert_eq!(xii.cycling_next(xii).value(), 1);
+    /// ```
+    pub fn cycling_next(self, max: Roman) -> Roman {
+        if self >= max {
+            Roman::MIN
+        } else {
+            GenericRoman(self.0.saturating_add(1))
+        }
+    }
+
+    /// Returns a [`Cycle`] which endlessly counts from this numeral up to `max`, then
+    /// starts again from `I`.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use xvii::Roman;
+    ///
+    /// let iv = Roman::new(4).unwrap();
+    /// let turns: Vec<_> = Roman::new(3).unwrap().cycle(iv).take(5).collect();
+    /// assert_eq!(turns.iter().map(|n| n.value()).collect::<Vec<_>>(), [3, 4, 1, 2, 3]);
+    /// ```
+    pub const fn cycle(self, max: Roman) -> Cycle {
+        Cycle { next: self, max }
+    }
+}
+
+/// An endless iterator of numerals which wraps around at a maximum.
+///
+/// This struct is created by the [`cycle`](Roman::cycle) method.
+#[derive(Debug, Copy, Clone)]
+pub struct Cycle {
+    next: Roman,
+    max: Roman,
+}
+
+impl Iterator for Cycle {
+    type Item = Roman;
+
+    fn next(&mut self) -> Option<Roman> {
+        let current = self.next;
+        self.next = current.cycling_next(self.max);
+        Some(current)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+}
+
+impl FusedIterator for Cycle {}
+
+#[cfg(test)]
+mod tests {
+    use crate::Roman;
+
+    #[test]
+    fn wraps_above_max() {
+        let v = Roman::new(5).unwrap();
+        assert_eq!(1, Roman::new(4999).unwrap().cycling_next(v).value());
+    }
+
+    #[test]
+    fn cycle_of_one() {
+        let i = Roman::new(1).unwrap();
+        assert!(i.cycle(i).take(3).all(|n| n == i));
+    }
+}
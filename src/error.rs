@@ -1,29 +1,218 @@
+use crate::Issue;
 use core::fmt::{self, Display};
 
 /// An error in parsing a Roman numeral.
+///
+/// This implements [`core::error::Error`] whether or not the `std` feature is
+/// enabled, so that `no_std` code can propagate it like any other error.
+///
+/// With the `serde` feature, errors serialize in serde's default enum representation,
+/// e.g. `{"InvalidDigit": [81, 3]}` or `"Overflow"`.
 #[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Error {
-    /// Encountered an invalid digit while parsing.
-    InvalidDigit(u8),
+    /// Encountered an invalid digit while parsing, at the given byte offset into the
+    /// numeral.
+    InvalidDigit(u8, usize),
+
+    /// Encountered a non-ASCII character which is not a digit, or a character which is
+    /// not a digit of a custom alphabet, at the given byte offset into the numeral.
+    InvalidChar(char, usize),
 
     /// Value out of range.
     OutOfRange(u16),
 
-    /// Value is way out of range (> 65536).
+    /// Value is zero, which has no numeral. Only constructors which single zero out,
+    /// such as [`Roman::try_new`](crate::Roman::try_new), return this; the others
+    /// report zero as `OutOfRange(0)`.
+    Zero,
+
+    /// Value is too far out of range to be carried by [`Error::OutOfRange`]: negative,
+    /// or greater than 65535.
     Overflow,
+
+    /// A digit was repeated more times in a row than a [`RomanParser`](crate::RomanParser)
+    /// allows.
+    TooManyRepeats(char),
+
+    /// A smaller digit was written before a larger one in a way a
+    /// [`RomanParser`](crate::RomanParser) does not allow, as in `IC` when only the
+    /// classical subtractive pairs are accepted.
+    InvalidPair(char, char),
+
+    /// A numeral was read in a spelling other than the canonical one, by
+    /// [`Roman::parse_canonical`](crate::Roman::parse_canonical).
+    NonCanonical {
+        /// The value of the numeral, whose canonical spelling may be offered instead.
+        value: u16,
+        /// The first rule of canonical notation the numeral breaks.
+        issue: Issue,
+    },
+}
+
+impl Error {
+    /// Returns the byte offset into the numeral at which the error was found, for
+    /// errors which have one.
+    pub const fn offset(&self) -> Option<usize> {
+        match self {
+            Error::InvalidDigit(_, offset) | Error::InvalidChar(_, offset) => Some(*offset),
+            Error::NonCanonical { issue, .. } => Some(issue.offset),
+            _ => None,
+        }
+    }
+
+    /// Returns the part of `input` around the offending digit: the digit itself and
+    /// a character to either side.
+    ///
+    /// `input` should be the string whose parsing failed. Returns `None` for errors
+    /// without an [`offset`](Error::offset), or if the offset does not fall within
+    /// `input`.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use xvii::Roman;
+    ///
+    /// let input = "MCMXQIV";
+    /// let e = input.parse::<Roman>().unwrap_err();
+    /// assert_eq!(e.context(input), Some("XQI"));
+    /// ```
+    pub fn context<'a>(&self, input: &'a str) -> Option<&'a str> {
+        let offset = self.offset()?;
+        let after = input.get(offset..).filter(|rest| !rest.is_empty())?;
+        let start = input[..offset]
+            .chars()
+            .next_back()
+            .map_or(offset, |c| offset - c.len_utf8());
+        let end = offset + after.chars().take(2).map(char::len_utf8).sum::<usize>();
+        Some(&input[start..end])
+    }
+
+    /// Pairs the error with the string whose parsing failed, so that it displays
+    /// along with the part of `input` where it was found.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use xvii::Roman;
+    ///
+    /// let input = "XQI";
+    /// let e = input.parse::<Roman>().unwrap_err();
+    /// assert_eq!(
+    ///     e.in_context(input).to_string(),
+    ///     "invalid numeral near \"XQI\": Parser encountered an invalid digit: Q (at byte 1)"
+    /// );
+    /// ```
+    pub fn in_context(self, input: &str) -> ErrorContext<'_> {
+        ErrorContext { error: self, input }
+    }
+
+    /// Moves the offset of an error found in part of a numeral `by` bytes along, to
+    /// give it relative to the whole.
+    pub(crate) const fn shifted(self, by: usize) -> Error {
+        match self {
+            Error::InvalidDigit(digit, offset) => Error::InvalidDigit(digit, offset + by),
+            Error::InvalidChar(c, offset) => Error::InvalidChar(c, offset + by),
+            Error::NonCanonical { value, issue } => Error::NonCanonical {
+                value,
+                issue: Issue {
+                    offset: issue.offset + by,
+                    ..issue
+                },
+            },
+            e => e,
+        }
+    }
 }
 
 impl Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
-            Error::InvalidDigit(digit) => {
-                write!(f, "Parser encountered an invalid digit: {}", *digit as char)
-            }
+            Error::InvalidDigit(digit, offset) => write!(
+                f,
+                "Parser encountered an invalid digit: {} (at byte {})",
+                *digit as char, offset
+            ),
+            Error::InvalidChar(c, offset) => write!(
+                f,
+                "Parser encountered an invalid character: {} (at byte {})",
+                c, offset
+            ),
             Error::OutOfRange(value) => write!(f, "Value out of range: {}", value),
+            Error::Zero => f.write_str("Value out of range: zero has no numeral"),
             Error::Overflow => f.write_str("Value out of range"),
+            Error::TooManyRepeats(c) => write!(f, "Digit repeated too many times: {}", c),
+            Error::InvalidPair(smaller, larger) => {
+                write!(f, "Invalid subtractive pair: {}{}", smaller, larger)
+            }
+            Error::NonCanonical { issue, .. } => {
+                write!(f, "Numeral not in canonical form: {}", issue)
+            }
         }
     }
 }
 
-#[cfg(feature = "std")]
-impl std::error::Error for Error {}
+impl core::error::Error for Error {}
+
+/// An [`Error`] along with the string whose parsing failed.
+///
+/// This struct is created by the [`in_context`](Error::in_context) method.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct ErrorContext<'a> {
+    error: Error,
+    input: &'a str,
+}
+
+impl ErrorContext<'_> {
+    /// Returns the error.
+    pub const fn error(&self) -> Error {
+        self.error
+    }
+}
+
+impl Display for ErrorContext<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.error.context(self.input) {
+            Some(context) => write!(f, "invalid numeral near {:?}: {}", context, self.error),
+            None => write!(f, "invalid numeral {:?}: {}", self.input, self.error),
+        }
+    }
+}
+
+impl core::error::Error for ErrorContext<'_> {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Error;
+    use crate::{Roman, Roman32};
+
+    #[test]
+    fn context() {
+        fn context(s: &str) -> Option<&str> {
+            s.parse::<Roman>().unwrap_err().context(s)
+        }
+        assert_eq!(Some("QX"), context("QXI"));
+        assert_eq!(Some("XQ"), context("XQ"));
+        assert_eq!(Some("X Ⅻ"), context("MX ⅫI"));
+        assert_eq!(Some("XↀI"), context("MXↀI"));
+        assert_eq!(None, context("MMMMM"));
+        assert_eq!(None, Error::InvalidDigit(b'Q', 9).context("XQI"));
+
+        let input = "(X(I)";
+        let e = input.parse::<Roman32>().unwrap_err();
+        assert_eq!(Some("(X"), e.context(input));
+    }
+
+    #[test]
+    fn in_context() {
+        let e = Error::OutOfRange(5000);
+        assert_eq!(
+            "invalid numeral \"MMMMM\": Value out of range: 5000",
+            e.in_context("MMMMM").to_string()
+        );
+    }
+}
@@ -0,0 +1,241 @@
+//! Evaluation of arithmetic written in Roman numerals.
+//!
+//! [`eval`] accepts expressions such as `"XVII + IV * II"`, built from numerals,
+//! the operators `+`, `-`, `*` and `/`, and parentheses. Multiplication and division
+//! bind more tightly than addition and subtraction, and operators of equal precedence
+//! are applied from left to right. Whitespace is ignored.
+//!
+//! Intermediate results may fall outside the range of a numeral, so
+//! `(I - V) * (II - III)` evaluates to `IV`, and an operand such as `MMMMM` is
+//! accepted although it could not be parsed alone; only the final result must be in
+//! the range `1..=4999`. Division discards any remainder, since Roman numerals have
+//! no way of writing one.
+//!
+//! ```rust
+//! use xvii::eval::{eval, EvalError};
+//!
+//! assert_eq!(eval("XVII + IV * II").unwrap().value(), 25);
+//! assert_eq!(eval("(XVII + IV) * II").unwrap().value(), 42);
+//! assert_eq!(eval("X - X"), Err(EvalError::OutOfRange(0)));
+//! ```
+
+use crate::{unit, Error, Roman};
+use core::{
+    convert::TryFrom,
+    fmt::{self, Display},
+};
+
+/// Parentheses nested deeper than this are rejected rather than risk the stack.
+const MAX_DEPTH: usize = 64;
+
+/// An error in evaluating an expression.
+///
+/// Positions are byte offsets into the expression.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum EvalError {
+    /// The numeral beginning at the given position is not valid. The offset of the
+    /// error, if it has one, is also into the whole expression.
+    Numeral(usize, Error),
+
+    /// Encountered a character which cannot appear at the given position.
+    UnexpectedChar(usize, char),
+
+    /// The expression ended where a numeral or `(` was expected.
+    UnexpectedEnd,
+
+    /// The divisor of the `/` at the given position evaluated to zero.
+    DivisionByZero(usize),
+
+    /// Parentheses are nested too deeply, starting at the given position.
+    TooDeep(usize),
+
+    /// The expression evaluated to a value which no numeral can represent.
+    OutOfRange(i64),
+
+    /// An intermediate result overflowed.
+    Overflow,
+}
+
+impl Display for EvalError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            EvalError::Numeral(pos, e) => write!(f, "Invalid numeral at {}: {}", pos, e),
+            EvalError::UnexpectedChar(pos, c) => {
+                write!(f, "Unexpected character at {}: {}", pos, c)
+            }
+            EvalError::UnexpectedEnd => f.write_str("Unexpected end of expression"),
+            EvalError::DivisionByZero(pos) => write!(f, "Division by zero at {}", pos),
+            EvalError::TooDeep(pos) => write!(f, "Parentheses nested too deeply at {}", pos),
+            EvalError::OutOfRange(value) => write!(f, "Result out of range: {}", value),
+            EvalError::Overflow => f.write_str("Intermediate result overflowed"),
+        }
+    }
+}
+
+impl core::error::Error for EvalError {}
+
+/// Evaluates an arithmetic expression written in Roman numerals.
+///
+/// See the [module documentation](self) for the syntax.
+pub fn eval(expr: &str) -> Result<Roman, EvalError> {
+    let mut parser = Parser { expr, pos: 0 };
+    let value = parser.expr(0)?;
+
+    parser.skip_whitespace();
+    if let Some(c) = parser.peek() {
+        return Err(EvalError::UnexpectedChar(parser.pos, c));
+    }
+
+    u16::try_from(value)
+        .ok()
+        .and_then(|n| Roman::new(n).ok())
+        .ok_or(EvalError::OutOfRange(value))
+}
+
+struct Parser<'a> {
+    expr: &'a str,
+    pos: usize,
+}
+
+impl Parser<'_> {
+    fn expr(&mut self, depth: usize) -> Result<i64, EvalError> {
+        let mut value = self.term(depth)?;
+
+        loop {
+            self.skip_whitespace();
+            let op = match self.peek() {
+                Some(c @ '+') | Some(c @ '-') => c,
+                _ => return Ok(value),
+            };
+            self.pos += 1;
+
+            let rhs = self.term(depth)?;
+            value = match op {
+                '+' => value.checked_add(rhs),
+                _ => value.checked_sub(rhs),
+            }
+            .ok_or(EvalError::Overflow)?;
+        }
+    }
+
+    fn term(&mut self, depth: usize) -> Result<i64, EvalError> {
+        let mut value = self.factor(depth)?;
+
+        loop {
+            self.skip_whitespace();
+            let op = match self.peek() {
+                Some(c @ '*') | Some(c @ '/') => c,
+                _ => return Ok(value),
+            };
+            let op_pos = self.pos;
+            self.pos += 1;
+
+            let rhs = self.factor(depth)?;
+            value = match op {
+                '*' => value.checked_mul(rhs).ok_or(EvalError::Overflow)?,
+                _ if rhs == 0 => return Err(EvalError::DivisionByZero(op_pos)),
+                _ => value.checked_div(rhs).ok_or(EvalError::Overflow)?,
+            };
+        }
+    }
+
+    fn factor(&mut self, depth: usize) -> Result<i64, EvalError> {
+        self.skip_whitespace();
+        let start = self.pos;
+
+        match self.peek() {
+            None => Err(EvalError::UnexpectedEnd),
+            Some('(') => {
+                if depth == MAX_DEPTH {
+                    return Err(EvalError::TooDeep(start));
+                }
+                self.pos += 1;
+
+                let value = self.expr(depth + 1)?;
+                self.skip_whitespace();
+                match self.peek() {
+                    Some(')') => {
+                        self.pos += 1;
+                        Ok(value)
+                    }
+                    Some(c) => Err(EvalError::UnexpectedChar(self.pos, c)),
+                    None => Err(EvalError::UnexpectedEnd),
+                }
+            }
+            Some(c) if c.is_ascii_alphabetic() => {
+                let len = self.expr[start..]
+                    .find(|c: char| !c.is_ascii_alphabetic())
+                    .unwrap_or(self.expr.len() - start);
+                self.pos += len;
+
+                unit::sum(&self.expr[start..self.pos])
+                    .map(i64::from)
+                    .map_err(|e| EvalError::Numeral(start, e.shifted(start)))
+            }
+            Some(c) => Err(EvalError::UnexpectedChar(start, c)),
+        }
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.expr[self.pos..].chars().next()
+    }
+
+    fn skip_whitespace(&mut self) {
+        let rest = &self.expr[self.pos..];
+        self.pos += rest.len() - rest.trim_start().len();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{eval, EvalError};
+    use crate::Error;
+
+    fn value(expr: &str) -> u16 {
+        eval(expr).unwrap().value()
+    }
+
+    #[test]
+    fn precedence_and_associativity() {
+        assert_eq!(25, value("XVII + IV * II"));
+        assert_eq!(5, value("X - III - II"));
+        assert_eq!(3, value("XXIV / IV / II"));
+        assert_eq!(42, value("(XVII+IV)*II"));
+    }
+
+    #[test]
+    fn intermediate_values_may_leave_range() {
+        assert_eq!(4, value("(I - V) * (II - III)"));
+        assert_eq!(4999, value("MMMMM - I"));
+    }
+
+    #[test]
+    fn division_truncates() {
+        assert_eq!(8, value("XVII / II"));
+        assert_eq!(
+            EvalError::DivisionByZero(2),
+            eval("X / (V - V)").unwrap_err()
+        );
+    }
+
+    #[test]
+    fn reports_positions() {
+        assert_eq!(
+            Err(EvalError::Numeral(4, Error::InvalidDigit(b'Q', 5))),
+            eval("X + XQ")
+        );
+        match eval("(II * MXQI)") {
+            Err(EvalError::Numeral(6, e)) => assert_eq!(Some("XQI"), e.context("(II * MXQI)")),
+            other => panic!("unexpected result: {:?}", other),
+        }
+        assert_eq!(Err(EvalError::UnexpectedChar(2, '%')), eval("X % II"));
+        assert_eq!(Err(EvalError::UnexpectedChar(3, ')')), eval("(X))"));
+        assert_eq!(Err(EvalError::UnexpectedEnd), eval("(X + "));
+    }
+
+    #[test]
+    fn deep_nesting_is_rejected() {
+        let expr = "(".repeat(100) + "I" + &")".repeat(100);
+        assert_eq!(Err(EvalError::TooDeep(64)), eval(&expr));
+    }
+}
@@ -0,0 +1,314 @@
+use crate::{unit, Error, Result, Roman, Style};
+use core::{
+    fmt::{self, Display, Write},
+    ops::{Add, Sub},
+    str::FromStr,
+};
+
+/// Dot signs for one to five unciae, as written by the formatter.
+const DOTS: [&str; 6] = ["", "·", ":", "∴", "∷", "⁙"];
+
+/// A numeral with a fractional part in twelfths.
+///
+/// Roman fractions counted in unciae, or twelfths. Half is written `S` (semis) and
+/// each remaining uncia as a dot, so that 2¼ is `II∴` and 7⅚ is `VIIS∷`. The
+/// formatter writes the dots with the signs `·`, `:`, `∴`, `∷` and `⁙` for one to
+/// five unciae; the parser also accepts `.` for a single dot, and any combination of
+/// signs adding up to five or fewer.
+///
+/// ## Examples
+///
+/// ```
+/// use xvii::RomanFraction;
+///
+/// let sestertius: RomanFraction = "IIS".parse().unwrap();
+/// assert_eq!(sestertius.whole().unwrap().value(), 2);
+/// assert_eq!(sestertius.twelfths(), 6);
+///
+/// assert_eq!(RomanFraction::new(0, 7).unwrap().to_string(), "S·");
+/// ```
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct RomanFraction {
+    whole: u16,
+    twelfths: u8,
+}
+
+impl RomanFraction {
+    /// Creates a fraction of `whole` plus `twelfths` twelfths.
+    ///
+    /// Fails with [`Error::OutOfRange`] if `whole` is above 4999 or `twelfths` above
+    /// 11, and with [`Error::Zero`] if both are zero.
+    pub const fn new(whole: u16, twelfths: u8) -> Result<RomanFraction> {
+        match (whole, twelfths) {
+            (0, 0) => Err(Error::Zero),
+            (5000..=u16::MAX, _) => Err(Error::OutOfRange(whole)),
+            (_, 12..=u8::MAX) => Err(Error::OutOfRange(twelfths as u16)),
+            _ => Ok(RomanFraction { whole, twelfths }),
+        }
+    }
+
+    /// Returns the whole part, if there is one.
+    pub const fn whole(self) -> Option<Roman> {
+        match Roman::new(self.whole) {
+            Ok(whole) => Some(whole),
+            Err(_) => None,
+        }
+    }
+
+    /// Returns the fractional part, in twelfths.
+    pub const fn twelfths(self) -> u8 {
+        self.twelfths
+    }
+
+    /// Adds two fractions, carrying whole twelfths into the whole part, and returning
+    /// `None` if the whole part of the sum is above 4999.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use xvii::RomanFraction;
+    ///
+    /// let a: RomanFraction = "IIS∴".parse().unwrap();
+    /// let b: RomanFraction = "S·".parse().unwrap();
+    /// assert_eq!(a.checked_add(b).unwrap().to_string(), "III∷");
+    /// ```
+    pub const fn checked_add(self, rhs: RomanFraction) -> Option<RomanFraction> {
+        RomanFraction::from_unciae(self.unciae() + rhs.unciae())
+    }
+
+    /// Subtracts one fraction from another, borrowing from the whole part, and
+    /// returning `None` if the difference is zero or negative.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use xvii::RomanFraction;
+    ///
+    /// let a: RomanFraction = "III".parse().unwrap();
+    /// let b: RomanFraction = "S·".parse().unwrap();
+    /// assert_eq!(a.checked_sub(b).unwrap().to_string(), "II⁙");
+    /// assert_eq!(b.checked_sub(a), None);
+    /// ```
+    pub const fn checked_sub(self, rhs: RomanFraction) -> Option<RomanFraction> {
+        match self.unciae().checked_sub(rhs.unciae()) {
+            Some(unciae) => RomanFraction::from_unciae(unciae),
+            None => None,
+        }
+    }
+
+    /// Returns the value in twelfths.
+    const fn unciae(self) -> u32 {
+        self.whole as u32 * 12 + self.twelfths as u32
+    }
+
+    const fn from_unciae(unciae: u32) -> Option<RomanFraction> {
+        match (unciae / 12, unciae % 12) {
+            (0, 0) | (5000..=u32::MAX, _) => None,
+            (whole, twelfths) => Some(RomanFraction {
+                whole: whole as u16,
+                twelfths: twelfths as u8,
+            }),
+        }
+    }
+
+    /// Returns a [`FractionFormatter`] which lazily formats `self` in the given style.
+    ///
+    /// Lowercase style writes the semis as `s`.
+    pub const fn format(self, style: Style) -> FractionFormatter {
+        FractionFormatter {
+            fraction: self,
+            style,
+        }
+    }
+}
+
+impl Add for RomanFraction {
+    type Output = RomanFraction;
+
+    #[track_caller]
+    fn add(self, rhs: RomanFraction) -> RomanFraction {
+        self.checked_add(rhs)
+            .expect("fraction addition out of range")
+    }
+}
+
+impl Sub for RomanFraction {
+    type Output = RomanFraction;
+
+    #[track_caller]
+    fn sub(self, rhs: RomanFraction) -> RomanFraction {
+        self.checked_sub(rhs)
+            .expect("fraction subtraction out of range")
+    }
+}
+
+impl From<Roman> for RomanFraction {
+    fn from(n: Roman) -> Self {
+        RomanFraction {
+            whole: n.value(),
+            twelfths: 0,
+        }
+    }
+}
+
+/// Lazy formatter for [`RomanFraction`] values.
+///
+/// This struct is created by the [`format`](RomanFraction::format) method.
+#[derive(Debug, Copy, Clone)]
+pub struct FractionFormatter {
+    fraction: RomanFraction,
+    style: Style,
+}
+
+impl Display for FractionFormatter {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if let Some(whole) = self.fraction.whole() {
+            whole.format(self.style).fmt(f)?;
+        }
+
+        let twelfths = usize::from(self.fraction.twelfths);
+        if twelfths >= 6 {
+            match self.style {
+                Style::Lower => f.write_char('s')?,
+                Style::Upper | Style::Unicode | Style::Clock => f.write_char('S')?,
+            }
+        }
+        f.write_str(DOTS[twelfths % 6])
+    }
+}
+
+impl Display for RomanFraction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let style = crate::global_style::display_style();
+
+        self.format(style).fmt(f)
+    }
+}
+
+impl FromStr for RomanFraction {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let split = s.find(|c| c == 'S' || c == 's' || dots(c).is_some());
+        let (whole, fraction) = s.split_at(split.unwrap_or(s.len()));
+        let whole = match whole {
+            "" => 0,
+            _ => unit::sum(whole)?,
+        };
+
+        let mut chars = fraction
+            .char_indices()
+            .map(|(i, c)| (s.len() - fraction.len() + i, c))
+            .peekable();
+        let mut twelfths = match chars.peek() {
+            Some((_, 'S')) | Some((_, 's')) => {
+                chars.next();
+                6
+            }
+            _ => 0,
+        };
+
+        let mut unciae = 0;
+        for (offset, c) in chars {
+            unciae += dots(c).ok_or_else(|| invalid(c, offset))?;
+            if unciae > 5 {
+                return Err(invalid(c, offset));
+            }
+        }
+        twelfths += unciae;
+
+        RomanFraction::new(whole, twelfths)
+    }
+}
+
+/// Returns the number of unciae a dot sign stands for.
+fn dots(c: char) -> Option<u8> {
+    match c {
+        '·' | '.' => Some(1),
+        ':' => Some(2),
+        '∴' => Some(3),
+        '∷' => Some(4),
+        '⁙' => Some(5),
+        _ => None,
+    }
+}
+
+fn invalid(c: char, offset: usize) -> Error {
+    match c {
+        '\0'..='\x7f' => Error::InvalidDigit(c as u8, offset),
+        _ => Error::InvalidChar(c, offset),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RomanFraction;
+    use crate::{Error, Style};
+
+    fn fraction(whole: u16, twelfths: u8) -> RomanFraction {
+        RomanFraction::new(whole, twelfths).unwrap()
+    }
+
+    #[test]
+    fn formats_every_twelfth() {
+        let formatted: Vec<_> = (1..12).map(|n| fraction(0, n).to_string()).collect();
+        assert_eq!(
+            vec!["·", ":", "∴", "∷", "⁙", "S", "S·", "S:", "S∴", "S∷", "S⁙"],
+            formatted
+        );
+        assert_eq!("xiis∷", fraction(12, 10).format(Style::Lower).to_string());
+    }
+
+    #[test]
+    fn parses_dot_combinations() {
+        assert_eq!(Ok(fraction(2, 3)), "II∴".parse());
+        assert_eq!(Ok(fraction(7, 11)), "viis:.:".parse());
+        assert_eq!(Ok(fraction(0, 6)), "S".parse());
+        assert_eq!(Ok(fraction(4999, 0)), "MMMMCMXCIX".parse());
+    }
+
+    #[test]
+    fn arithmetic_carries() {
+        assert_eq!(
+            Some(fraction(3, 4)),
+            fraction(2, 9).checked_add(fraction(0, 7))
+        );
+        assert_eq!(
+            Some(fraction(1, 0)),
+            fraction(0, 6).checked_add(fraction(0, 6))
+        );
+        assert_eq!(None, fraction(4999, 11).checked_add(fraction(0, 1)));
+        assert_eq!(
+            Some(fraction(1, 11)),
+            fraction(2, 0).checked_sub(fraction(0, 1))
+        );
+        assert_eq!(None, fraction(2, 3).checked_sub(fraction(2, 3)));
+        assert_eq!(None, fraction(0, 1).checked_sub(fraction(1, 0)));
+        assert_eq!(fraction(5, 1), fraction(2, 7) + fraction(2, 6));
+        assert_eq!(fraction(0, 1), fraction(2, 7) - fraction(2, 6));
+    }
+
+    #[test]
+    #[should_panic(expected = "fraction subtraction out of range")]
+    fn sub_to_zero() {
+        let _ = fraction(1, 0) - fraction(1, 0);
+    }
+
+    #[test]
+    fn rejects_bad_input() {
+        assert_eq!(
+            Err(Error::InvalidDigit(b':', 4)),
+            "S∷:".parse::<RomanFraction>()
+        );
+        assert_eq!(
+            Err(Error::InvalidDigit(b'S', 1)),
+            "SS".parse::<RomanFraction>()
+        );
+        assert_eq!(
+            Err(Error::InvalidDigit(b'I', 3)),
+            "S·I".parse::<RomanFraction>()
+        );
+        assert_eq!(Err(Error::Zero), "".parse::<RomanFraction>());
+        assert_eq!(Err(Error::OutOfRange(12)), RomanFraction::new(1, 12));
+    }
+}
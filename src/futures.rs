@@ -0,0 +1,205 @@
+//! Extracting numerals from an asynchronous byte stream.
+//!
+//! [`numerals`] wraps a [`Stream`] of byte chunks, such as a network response body,
+//! and yields each numeral found in the text along with its byte offset from the
+//! start of the stream. Chunks are examined as they arrive and numerals split
+//! across chunk boundaries are still found, so the payload never needs to be
+//! buffered in full.
+//!
+//! Only whole words are considered, and only those written entirely in one case
+//! and in canonical form, so that ordinary words which happen to be spelled with
+//! numeral letters ("mid", "civil", "Mix") are not reported. Words such as "I" and
+//! "mix" are indistinguishable from numerals and will be.
+
+use crate::{classify, roman::ladder::MAX_LEN, NumeralForm, Roman};
+use core::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+use futures_core::Stream;
+use std::collections::VecDeque;
+
+/// Wraps a stream of byte chunks in a stream of the numerals it contains.
+///
+/// Each item yielded is the byte offset at which a numeral begins, paired with its
+/// value. An error from the underlying stream is passed through; the stream may be
+/// polled again afterwards if the underlying stream allows it.
+pub fn numerals<S, B, E>(stream: S) -> Numerals<S>
+where
+    S: Stream<Item = Result<B, E>> + Unpin,
+    B: AsRef<[u8]>,
+{
+    Numerals {
+        stream,
+        pending: VecDeque::new(),
+        extractor: Extractor::default(),
+        done: false,
+    }
+}
+
+/// Stream of numerals found in a stream of byte chunks.
+///
+/// This struct is created by the [`numerals`] function.
+#[derive(Debug)]
+pub struct Numerals<S> {
+    stream: S,
+    pending: VecDeque<(usize, Roman)>,
+    extractor: Extractor,
+    done: bool,
+}
+
+impl<S, B, E> Stream for Numerals<S>
+where
+    S: Stream<Item = Result<B, E>> + Unpin,
+    B: AsRef<[u8]>,
+{
+    type Item = Result<(usize, Roman), E>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = &mut *self;
+        loop {
+            if let Some(found) = this.pending.pop_front() {
+                return Poll::Ready(Some(Ok(found)));
+            }
+
+            if this.done {
+                return Poll::Ready(None);
+            }
+
+            match Pin::new(&mut this.stream).poll_next(cx) {
+                Poll::Pending => return Poll::Pending,
+                Poll::Ready(Some(Ok(chunk))) => {
+                    for &byte in chunk.as_ref() {
+                        this.extractor.push(byte, &mut this.pending);
+                    }
+                }
+                Poll::Ready(Some(Err(e))) => return Poll::Ready(Some(Err(e))),
+                Poll::Ready(None) => {
+                    this.extractor.end_word(&mut this.pending);
+                    this.done = true;
+                }
+            }
+        }
+    }
+}
+
+/// Splits bytes into words and keeps those which could be numerals.
+#[derive(Debug, Default)]
+struct Extractor {
+    offset: usize,
+    start: usize,
+    word: [u8; MAX_LEN],
+    len: usize,
+    in_word: bool,
+    candidate: bool,
+}
+
+impl Extractor {
+    fn push(&mut self, byte: u8, found: &mut VecDeque<(usize, Roman)>) {
+        // Non-ASCII bytes are treated as letters, so that a numeral is never
+        // carved out of a word containing accented characters.
+        if byte.is_ascii_alphanumeric() || !byte.is_ascii() {
+            if !self.in_word {
+                self.in_word = true;
+                self.candidate = true;
+                self.start = self.offset;
+                self.len = 0;
+            }
+
+            if self.candidate && self.len < MAX_LEN && crate::unit::to_digit(byte).is_some() {
+                self.word[self.len] = byte;
+                self.len += 1;
+            } else {
+                self.candidate = false;
+            }
+        } else {
+            self.end_word(found);
+        }
+
+        self.offset += 1;
+    }
+
+    fn end_word(&mut self, found: &mut VecDeque<(usize, Roman)>) {
+        if !self.in_word {
+            return;
+        }
+        self.in_word = false;
+
+        let word = &self.word[..self.len];
+        let uniform =
+            word.iter().all(u8::is_ascii_uppercase) || word.iter().all(u8::is_ascii_lowercase);
+        if !self.candidate || !uniform {
+            return;
+        }
+
+        // Every byte is an ASCII numeral letter, so this cannot fail.
+        let s = core::str::from_utf8(word).unwrap();
+        if classify(s) == Ok(NumeralForm::Canonical) {
+            if let Ok(roman) = s.parse() {
+                found.push_back((self.start, roman));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::numerals;
+    use core::{
+        pin::Pin,
+        task::{Context, Poll, Waker},
+    };
+    use futures_core::Stream;
+    use std::{sync::Arc, task::Wake};
+
+    /// A waker which does nothing, for polling a stream which is always ready.
+    struct Noop;
+
+    impl Wake for Noop {
+        fn wake(self: Arc<Self>) {}
+    }
+
+    /// An always-ready stream over a list of chunks.
+    struct Chunks(Vec<Result<&'static str, ()>>);
+
+    impl Stream for Chunks {
+        type Item = Result<&'static str, ()>;
+
+        fn poll_next(mut self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+            Poll::Ready(if self.0.is_empty() {
+                None
+            } else {
+                Some(self.0.remove(0))
+            })
+        }
+    }
+
+    fn collect(chunks: Vec<Result<&'static str, ()>>) -> Vec<Result<(usize, u16), ()>> {
+        let mut stream = numerals(Chunks(chunks));
+        let waker = Waker::from(Arc::new(Noop));
+        let mut cx = Context::from_waker(&waker);
+        let mut items = Vec::new();
+        while let Poll::Ready(Some(item)) = Pin::new(&mut stream).poll_next(&mut cx) {
+            items.push(item.map(|(offset, roman)| (offset, roman.value())));
+        }
+        items
+    }
+
+    #[test]
+    fn finds_numerals_across_chunks() {
+        let items = collect(vec![Ok("Chapter X"), Ok("VII, then Act "), Ok("iv.")]);
+        assert_eq!(vec![Ok((8, 17)), Ok((23, 4))], items);
+    }
+
+    #[test]
+    fn skips_words_and_mixed_case() {
+        let items = collect(vec![Ok("a civil Mix of mid-XIIV and MCM")]);
+        assert_eq!(vec![Ok((28, 1900))], items);
+    }
+
+    #[test]
+    fn passes_errors_through() {
+        let items = collect(vec![Ok("I "), Err(()), Ok("II")]);
+        assert_eq!(vec![Ok((0, 1)), Err(()), Ok((2, 2))], items);
+    }
+}
@@ -0,0 +1,72 @@
+use crate::Style;
+#[cfg(feature = "global-style")]
+use core::sync::atomic::{AtomicU8, Ordering};
+
+#[cfg(feature = "global-style")]
+const UPPER: u8 = 0;
+#[cfg(feature = "global-style")]
+const LOWER: u8 = 1;
+#[cfg(feature = "global-style")]
+const UNICODE: u8 = 2;
+#[cfg(feature = "global-style")]
+const CLOCK: u8 = 3;
+
+#[cfg(feature = "global-style")]
+static DEFAULT_STYLE: AtomicU8 = AtomicU8::new(UPPER);
+
+/// Sets the style used when a [`Roman`](crate::Roman) is formatted through `Display`.
+///
+/// This affects `format!("{}")`, `to_string()` and everything else built on
+/// `Display`, including third-party code, for the whole process. It is meant for
+/// applications which want every numeral in one style; a library should format
+/// explicitly with [`Roman::format`](crate::Roman::format) instead of calling this.
+/// [`RomanFormatter`](crate::RomanFormatter) values are unaffected.
+///
+/// ## Examples
+///
+/// ```
+/// use xvii::{Roman, Style};
+///
+/// let seventeen = Roman::new(17).unwrap();
+/// assert_eq!(seventeen.to_string(), "XVII");
+///
+/// xvii::set_default_style(Style::Lower);
+/// assert_eq!(seventeen.to_string(), "xvii");
+/// assert_eq!(seventeen.format(Style::Upper).to_string(), "XVII");
+/// ```
+#[cfg(feature = "global-style")]
+pub fn set_default_style(style: Style) {
+    let style = match style {
+        Style::Upper => UPPER,
+        Style::Lower => LOWER,
+        Style::Unicode => UNICODE,
+        Style::Clock => CLOCK,
+    };
+    DEFAULT_STYLE.store(style, Ordering::Relaxed);
+}
+
+/// Returns the style used when a [`Roman`](crate::Roman) is formatted through
+/// `Display`, which is [`Style::Upper`] unless changed by [`set_default_style`].
+#[cfg(feature = "global-style")]
+pub fn default_style() -> Style {
+    match DEFAULT_STYLE.load(Ordering::Relaxed) {
+        LOWER => Style::Lower,
+        UNICODE => Style::Unicode,
+        CLOCK => Style::Clock,
+        _ => Style::Upper,
+    }
+}
+
+/// The style every `Display` impl in the crate formats with: the process-wide
+/// default when `global-style` is enabled, otherwise always [`Style::Upper`].
+#[cfg(feature = "global-style")]
+pub(crate) fn display_style() -> Style {
+    default_style()
+}
+
+/// The style every `Display` impl in the crate formats with: the process-wide
+/// default when `global-style` is enabled, otherwise always [`Style::Upper`].
+#[cfg(not(feature = "global-style"))]
+pub(crate) const fn display_style() -> Style {
+    Style::Upper
+}
@@ -0,0 +1,78 @@
+//! Godot integration via [gdext](https://godot-rust.github.io).
+//!
+//! `Roman` travels to and from Godot as an `int`, so it can be used directly in
+//! `#[func]` signatures and `#[var]` fields of your own classes. For use from GDScript,
+//! the [`RomanNumerals`] class exposes formatting and parsing as static functions:
+//!
+//! ```gdscript
+//! var label = RomanNumerals.format(wave, false)  # "XVII"
+//! var wave = RomanNumerals.parse("xvii")         # 17
+//! ```
+
+use crate::{Roman, Style};
+use godot::{
+    meta::{
+        conv::ByValue, error::ConvertError, shape::GodotShape, FromGodot, GodotConvert, ToGodot,
+    },
+    prelude::*,
+};
+use std::convert::TryFrom;
+
+impl GodotConvert for Roman {
+    type Via = i64;
+
+    fn godot_shape() -> GodotShape {
+        GodotShape::of_builtin::<i64>()
+    }
+}
+
+impl ToGodot for Roman {
+    type Pass = ByValue;
+
+    fn to_godot(&self) -> i64 {
+        self.value().into()
+    }
+}
+
+impl FromGodot for Roman {
+    fn try_from_godot(via: i64) -> Result<Self, ConvertError> {
+        match u16::try_from(via) {
+            Ok(n) => Roman::new(n).map_err(|e| ConvertError::with_error_value(e, via)),
+            Err(e) => Err(ConvertError::with_error_value(e, via)),
+        }
+    }
+}
+
+/// Roman numeral conversions for GDScript.
+///
+/// Registered with Godot as `RomanNumerals`; all of its functions are static.
+#[derive(Debug, GodotClass)]
+#[class(init, base = RefCounted)]
+pub struct RomanNumerals;
+
+#[godot_api]
+impl RomanNumerals {
+    /// Formats `value` as a Roman numeral, or returns an empty string if `value` is
+    /// outside the range `1..=4999`.
+    #[func]
+    pub fn format(value: i64, lowercase: bool) -> GString {
+        let style = if lowercase {
+            Style::Lower
+        } else {
+            Style::Upper
+        };
+        match Roman::try_from_godot(value) {
+            Ok(roman) => GString::from(&roman.format(style).to_string()),
+            Err(_) => GString::new(),
+        }
+    }
+
+    /// Parses a Roman numeral, returning `0` (which no numeral can represent) if
+    /// `text` is not a valid numeral.
+    #[func]
+    pub fn parse(text: GString) -> i64 {
+        text.to_string()
+            .parse::<Roman>()
+            .map_or(0, |roman| roman.value().into())
+    }
+}
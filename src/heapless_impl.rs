@@ -0,0 +1,38 @@
+use crate::{Roman, Style};
+use core::fmt::Write;
+use heapless::String;
+
+impl Roman {
+    /// Formats `self` into a [`heapless::String`] large enough for any numeral,
+    /// without allocating.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use xvii::{Roman, Style};
+    ///
+    /// let s = Roman::new(1984).unwrap().to_heapless_string(Style::Upper);
+    /// assert_eq!(s.as_str(), "MCMLXXXIV");
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "heapless")))]
+    pub fn to_heapless_string(self, style: Style) -> String<{ Roman::MAX_FORMATTED_LEN }> {
+        let mut s = String::new();
+        write!(s, "{}", self.format(style)).expect("numeral longer than MAX_FORMATTED_LEN");
+        s
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Roman, Style};
+    use heapless::String;
+
+    #[test]
+    fn formats_in_style() {
+        let n = Roman::new(1994).unwrap();
+        let s: String<{ Roman::MAX_FORMATTED_LEN }> = n.to_heapless_string(Style::Lower);
+        assert_eq!("mcmxciv", s.as_str());
+        assert_eq!(Roman::MAX_FORMATTED_LEN, s.capacity());
+        assert_eq!("MCMXCIIII", n.to_heapless_string(Style::Clock).as_str());
+    }
+}
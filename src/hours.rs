@@ -0,0 +1,241 @@
+//! Roman reckoning of the hours of the day.
+//!
+//! The Romans divided the daylight between sunrise and sunset into twelve equal
+//! hours, *hora prima* through *hora duodecima*, and the night into four watches,
+//! *vigilia prima* through *vigilia quarta*. Hours were therefore longer in summer
+//! than in winter. A [`Daylight`] records when the sun rises and sets, and converts
+//! modern clock times into a [`RomanHour`].
+//!
+//! Times are given in minutes since midnight.
+//!
+//! ```rust
+//! use xvii::hours::{Daylight, HourKind, RomanHour};
+//!
+//! // At the equinox, the first hour begins at six in the morning.
+//! let hour = Daylight::EQUINOX.hour(6 * 60).unwrap();
+//! assert_eq!(hour, RomanHour::new(HourKind::Day, 1).unwrap());
+//! assert_eq!(hour.to_string(), "hora I");
+//! assert_eq!(hour.latin(), "hora prima");
+//!
+//! // On midsummer's day in Rome, the sun rises at about 5:35 and sets at 20:50.
+//! let midsummer = Daylight::new(5 * 60 + 35, 20 * 60 + 50).unwrap();
+//! assert_eq!(midsummer.hour(20 * 60).unwrap().to_string(), "hora XII");
+//! assert_eq!(midsummer.hour(23 * 60).unwrap().to_string(), "vigilia I");
+//! ```
+
+use crate::{Error, Result, Roman};
+use core::fmt::{self, Display};
+
+const MINUTES_PER_DAY: u16 = 24 * 60;
+
+/// Whether a [`RomanHour`] is an hour of daylight or a watch of the night.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum HourKind {
+    /// An hour of daylight, from 1 to 12.
+    Day,
+    /// A watch of the night, from 1 to 4.
+    Night,
+}
+
+/// An hour of the day or a watch of the night, as the Romans counted them.
+///
+/// Formats as `hora` or `vigilia` followed by a numeral, e.g. `hora VI`.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct RomanHour {
+    kind: HourKind,
+    number: u8,
+}
+
+impl RomanHour {
+    /// Creates an hour of daylight or a watch of the night.
+    ///
+    /// Fails with [`Error::OutOfRange`] unless `number` is from 1 to 12 for an hour,
+    /// or from 1 to 4 for a watch.
+    pub const fn new(kind: HourKind, number: u8) -> Result<RomanHour> {
+        let max = match kind {
+            HourKind::Day => 12,
+            HourKind::Night => 4,
+        };
+        match number {
+            1.. if number <= max => Ok(RomanHour { kind, number }),
+            _ => Err(Error::OutOfRange(number as u16)),
+        }
+    }
+
+    /// Returns whether this is an hour of daylight or a watch of the night.
+    pub const fn kind(self) -> HourKind {
+        self.kind
+    }
+
+    /// Returns the number of the hour or watch.
+    pub const fn number(self) -> u8 {
+        self.number
+    }
+
+    /// Returns the Latin name of the hour or watch, e.g. `hora sexta`.
+    pub fn latin(self) -> &'static str {
+        static HORAE: [&str; 12] = [
+            "hora prima",
+            "hora secunda",
+            "hora tertia",
+            "hora quarta",
+            "hora quinta",
+            "hora sexta",
+            "hora septima",
+            "hora octava",
+            "hora nona",
+            "hora decima",
+            "hora undecima",
+            "hora duodecima",
+        ];
+        static VIGILIAE: [&str; 4] = [
+            "vigilia prima",
+            "vigilia secunda",
+            "vigilia tertia",
+            "vigilia quarta",
+        ];
+
+        let index = usize::from(self.number) - 1;
+        match self.kind {
+            HourKind::Day => HORAE[index],
+            HourKind::Night => VIGILIAE[index],
+        }
+    }
+}
+
+impl Display for RomanHour {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self.kind {
+            HourKind::Day => "hora",
+            HourKind::Night => "vigilia",
+        };
+
+        // Every hour and watch is numbered from 1 to 12 at most.
+        let numeral = Roman::new_saturating(self.number.into());
+        write!(f, "{} {}", name, numeral)
+    }
+}
+
+/// The times of sunrise and sunset on a given day, in minutes since midnight.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct Daylight {
+    sunrise: u16,
+    sunset: u16,
+}
+
+impl Daylight {
+    /// Twelve hours of daylight, from six in the morning to six in the evening.
+    pub const EQUINOX: Daylight = Daylight {
+        sunrise: 6 * 60,
+        sunset: 18 * 60,
+    };
+
+    /// Creates a `Daylight` from the times of sunrise and sunset.
+    ///
+    /// Returns `None` unless sunrise comes before sunset on the same day.
+    pub const fn new(sunrise: u16, sunset: u16) -> Option<Daylight> {
+        if sunrise < sunset && sunset <= MINUTES_PER_DAY {
+            Some(Daylight { sunrise, sunset })
+        } else {
+            None
+        }
+    }
+
+    /// Returns the time of sunrise.
+    pub const fn sunrise(&self) -> u16 {
+        self.sunrise
+    }
+
+    /// Returns the time of sunset.
+    pub const fn sunset(&self) -> u16 {
+        self.sunset
+    }
+
+    /// Converts a time of day into a Roman hour or night watch.
+    ///
+    /// Returns `None` if `minute` is not less than `24 * 60`.
+    pub fn hour(&self, minute: u16) -> Option<RomanHour> {
+        if minute >= MINUTES_PER_DAY {
+            return None;
+        }
+
+        let day = u32::from(self.sunset - self.sunrise);
+        if (self.sunrise..self.sunset).contains(&minute) {
+            let elapsed = u32::from(minute - self.sunrise);
+            Some(RomanHour {
+                kind: HourKind::Day,
+                number: (elapsed * 12 / day) as u8 + 1,
+            })
+        } else {
+            let night = u32::from(MINUTES_PER_DAY) - day;
+            let elapsed = u32::from((minute + MINUTES_PER_DAY - self.sunset) % MINUTES_PER_DAY);
+            Some(RomanHour {
+                kind: HourKind::Night,
+                number: (elapsed * 4 / night) as u8 + 1,
+            })
+        }
+    }
+}
+
+impl Default for Daylight {
+    fn default() -> Self {
+        Daylight::EQUINOX
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Daylight, HourKind, RomanHour};
+    use crate::Error;
+
+    fn day(n: u8) -> RomanHour {
+        RomanHour::new(HourKind::Day, n).unwrap()
+    }
+
+    fn night(n: u8) -> RomanHour {
+        RomanHour::new(HourKind::Night, n).unwrap()
+    }
+
+    #[test]
+    fn equinox_hours_are_sixty_minutes() {
+        let daylight = Daylight::EQUINOX;
+        assert_eq!(Some(day(1)), daylight.hour(6 * 60 + 59));
+        assert_eq!(Some(day(7)), daylight.hour(12 * 60));
+        assert_eq!(Some(day(12)), daylight.hour(17 * 60 + 59));
+    }
+
+    #[test]
+    fn night_watches_wrap_past_midnight() {
+        let daylight = Daylight::EQUINOX;
+        assert_eq!(Some(night(1)), daylight.hour(18 * 60));
+        assert_eq!(Some(night(3)), daylight.hour(0));
+        assert_eq!(Some(night(4)), daylight.hour(5 * 60 + 59));
+        assert_eq!(None, daylight.hour(24 * 60));
+    }
+
+    #[test]
+    fn sunrise_must_precede_sunset() {
+        assert_eq!(None, Daylight::new(18 * 60, 6 * 60));
+        assert_eq!(None, Daylight::new(0, 24 * 60 + 1));
+    }
+
+    #[test]
+    fn latin_names() {
+        assert_eq!("hora undecima", day(11).latin());
+        assert_eq!("vigilia quarta", night(4).latin());
+        assert_eq!("vigilia IV", night(4).to_string());
+    }
+
+    #[test]
+    fn numbers_are_checked() {
+        assert_eq!(Err(Error::OutOfRange(0)), RomanHour::new(HourKind::Day, 0));
+        assert_eq!(
+            Err(Error::OutOfRange(13)),
+            RomanHour::new(HourKind::Day, 13)
+        );
+        assert_eq!(
+            Err(Error::OutOfRange(5)),
+            RomanHour::new(HourKind::Night, 5)
+        );
+    }
+}
@@ -0,0 +1,229 @@
+use crate::{Error, Roman, Style};
+use core::{
+    fmt::{self, Display},
+    iter::FusedIterator,
+};
+
+/// One level of a nested enumeration label.
+///
+/// Produced by [`label_components`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum LabelComponent {
+    /// A Roman numeral, with the case it was written in. E.g.: `iv` or `IV`.
+    Roman(Roman, Style),
+    /// A single letter. E.g.: `b` or `A`.
+    Alpha(char),
+    /// An Arabic number. E.g.: `2`.
+    Arabic(u32),
+}
+
+/// An error in parsing an enumeration label.
+///
+/// Positions are byte offsets into the label.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum LabelError {
+    /// The character at the given position cannot begin or continue a component.
+    Unexpected(usize),
+    /// The parenthesis at the given position is never closed.
+    Unclosed(usize),
+    /// The word at the given position is longer than one letter but is not a numeral.
+    Numeral(usize, Error),
+    /// The number at the given position is too large.
+    Number(usize),
+}
+
+impl Display for LabelError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            LabelError::Unexpected(pos) => write!(f, "Unexpected character at {}", pos),
+            LabelError::Unclosed(pos) => write!(f, "Unclosed parenthesis at {}", pos),
+            LabelError::Numeral(pos, e) => write!(f, "Invalid numeral at {}: {}", pos, e),
+            LabelError::Number(pos) => write!(f, "Number too large at {}", pos),
+        }
+    }
+}
+
+impl core::error::Error for LabelError {}
+
+/// Splits a nested enumeration label, such as `(iv)(b)`, `IV.A.2` or `3(a)(ii)`, into
+/// its levels.
+///
+/// Levels are either wrapped in parentheses or separated by periods. A level of digits
+/// is Arabic, and a level of two or more letters must be a Roman numeral. A single
+/// letter is ambiguous (`(i)` could be a numeral or the letter after `h`), so it is read
+/// as a numeral only if it follows a lettered level, or if it is an `i` opening the
+/// label; otherwise it is a letter.
+///
+/// ## Examples
+///
+/// ```
+/// use xvii::{label_components, LabelComponent, Roman, Style};
+///
+/// let path: Result<Vec<_>, _> = label_components("IV.A.2").collect();
+/// assert_eq!(
+///     path.unwrap(),
+///     [
+///         LabelComponent::Roman(Roman::new(4).unwrap(), Style::Upper),
+///         LabelComponent::Alpha('A'),
+///         LabelComponent::Arabic(2),
+///     ]
+/// );
+///
+/// let path: Result<Vec<_>, _> = label_components("(b)(v)").collect();
+/// assert_eq!(path.unwrap()[1], LabelComponent::Roman(Roman::new(5).unwrap(), Style::Lower));
+/// ```
+pub fn label_components(label: &str) -> LabelComponents<'_> {
+    LabelComponents {
+        label,
+        pos: 0,
+        previous: None,
+    }
+}
+
+/// Iterator over the levels of an enumeration label.
+///
+/// This struct is created by the [`label_components`] function. It stops after the
+/// first error.
+#[derive(Debug, Clone)]
+pub struct LabelComponents<'a> {
+    label: &'a str,
+    pos: usize,
+    previous: Option<LabelComponent>,
+}
+
+impl LabelComponents<'_> {
+    fn fail(&mut self, e: LabelError) -> Option<Result<LabelComponent, LabelError>> {
+        self.pos = self.label.len();
+        Some(Err(e))
+    }
+
+    fn component(&self, start: usize, word: &str) -> Result<LabelComponent, LabelError> {
+        let bytes = word.as_bytes();
+
+        if bytes.iter().all(u8::is_ascii_digit) {
+            return word
+                .parse()
+                .map(LabelComponent::Arabic)
+                .map_err(|_| LabelError::Number(start));
+        }
+
+        let style = if bytes[0].is_ascii_lowercase() {
+            Style::Lower
+        } else {
+            Style::Upper
+        };
+
+        if let [letter] = bytes {
+            let opens_with_i = self.previous.is_none() && letter.eq_ignore_ascii_case(&b'i');
+            let after_letter = matches!(self.previous, Some(LabelComponent::Alpha(_)));
+            return match word.parse() {
+                Ok(n) if opens_with_i || after_letter => Ok(LabelComponent::Roman(n, style)),
+                _ if letter.is_ascii_alphabetic() => Ok(LabelComponent::Alpha(char::from(*letter))),
+                _ => Err(LabelError::Unexpected(start)),
+            };
+        }
+
+        match word.parse() {
+            Ok(n) => Ok(LabelComponent::Roman(n, style)),
+            Err(e) => Err(LabelError::Numeral(start, e)),
+        }
+    }
+}
+
+impl Iterator for LabelComponents<'_> {
+    type Item = Result<LabelComponent, LabelError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let rest = &self.label[self.pos..];
+        let skipped = rest.len()
+            - rest
+                .trim_start_matches(|c: char| c == '.' || c.is_whitespace())
+                .len();
+        self.pos += skipped;
+
+        let rest = &self.label[self.pos..];
+        let (start, word, consumed) = match rest.chars().next()? {
+            '(' => match rest.find(')') {
+                Some(end) => (self.pos + 1, &rest[1..end], end + 1),
+                None => return self.fail(LabelError::Unclosed(self.pos)),
+            },
+            _ => {
+                let len = rest
+                    .find(|c: char| !c.is_ascii_alphanumeric())
+                    .unwrap_or(rest.len());
+                (self.pos, &rest[..len], len)
+            }
+        };
+
+        if word.is_empty() || !word.bytes().all(|u| u.is_ascii_alphanumeric()) {
+            let bad = word.find(|c: char| !c.is_ascii_alphanumeric()).unwrap_or(0);
+            return self.fail(LabelError::Unexpected(start + bad));
+        }
+
+        match self.component(start, word) {
+            Ok(component) => {
+                self.pos += consumed;
+                self.previous = Some(component);
+                Some(Ok(component))
+            }
+            Err(e) => self.fail(e),
+        }
+    }
+}
+
+impl FusedIterator for LabelComponents<'_> {}
+
+#[cfg(test)]
+mod tests {
+    use super::{label_components, LabelComponent, LabelError};
+    use crate::{Error, Roman, Style};
+
+    fn parse(label: &str) -> Result<Vec<LabelComponent>, LabelError> {
+        label_components(label).collect()
+    }
+
+    fn roman(n: u16, style: Style) -> LabelComponent {
+        LabelComponent::Roman(Roman::new(n).unwrap(), style)
+    }
+
+    #[test]
+    fn parenthesized_levels() {
+        assert_eq!(
+            Ok(vec![roman(4, Style::Lower), LabelComponent::Alpha('b')]),
+            parse("(iv)(b)")
+        );
+        assert_eq!(
+            Ok(vec![
+                LabelComponent::Arabic(3),
+                LabelComponent::Alpha('a'),
+                roman(2, Style::Lower),
+            ]),
+            parse("3(a)(ii)")
+        );
+    }
+
+    #[test]
+    fn single_letters() {
+        assert_eq!(Ok(vec![roman(1, Style::Upper)]), parse("I."));
+        assert_eq!(
+            Ok(vec![LabelComponent::Alpha('h'), roman(1, Style::Lower)]),
+            parse("(h)(i)")
+        );
+        assert_eq!(
+            Ok(vec![roman(2, Style::Upper), LabelComponent::Alpha('C')]),
+            parse("II.C")
+        );
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!(Err(LabelError::Unclosed(3)), parse("(a)(iv"));
+        assert_eq!(Err(LabelError::Unexpected(1)), parse("()"));
+        assert_eq!(Err(LabelError::Unexpected(2)), parse("(a-b)"));
+        assert_eq!(
+            Err(LabelError::Numeral(2, Error::InvalidDigit(b'B', 0))),
+            parse("A.BC")
+        );
+        assert_eq!(Err(LabelError::Number(0)), parse("99999999999"));
+    }
+}
@@ -0,0 +1,186 @@
+use crate::{roman::ladder, Roman, Style};
+use core::{
+    iter::FusedIterator,
+    ops::{self, RangeInclusive},
+};
+
+impl Roman {
+    /// Returns the number of bytes `self` occupies when formatted in `style`, computed
+    /// without formatting it.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use xvii::{Roman, Style};
+    ///
+    /// let n = Roman::new(1984).unwrap();
+    /// assert_eq!(n.formatted_len(Style::Upper), 9);
+    /// assert_eq!(n.formatted_len(Style::Unicode), 24);
+    /// ```
+    pub fn formatted_len(self, style: Style) -> usize {
+        len_in(self.value(), style)
+    }
+
+    /// Returns the largest numeral which formats to no more than `len` bytes, or `None`
+    /// if not even `I` fits.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use xvii::{Roman, Style};
+    ///
+    /// assert_eq!(Roman::max_value_for_len(0, Style::Upper), None);
+    /// assert_eq!(Roman::max_value_for_len(1, Style::Upper).unwrap().value(), 1000);
+    /// assert_eq!(Roman::max_value_for_len(5, Style::Upper).unwrap().to_string(), "MMMMD");
+    /// ```
+    pub fn max_value_for_len(len: usize, style: Style) -> Option<Roman> {
+        (1..=4999)
+            .rev()
+            .find(|&value| len_in(value, style) <= len)
+            .and_then(|value| Roman::new(value).ok())
+    }
+}
+
+/// Returns an iterator over the formatted length of every numeral in `range`.
+///
+/// Lengths are computed without formatting anything, which makes this suitable for
+/// laying out tables of contents or estimating pagination ahead of time.
+///
+/// ## Examples
+///
+/// ```
+/// use xvii::{formatted_lengths, Roman, Style};
+///
+/// let range = Roman::new(1).unwrap()..=Roman::new(5).unwrap();
+/// let lengths: Vec<_> = formatted_lengths(range, Style::Upper).collect();
+/// assert_eq!(lengths, [1, 2, 3, 2, 1]);
+/// ```
+pub fn formatted_lengths(range: RangeInclusive<Roman>, style: Style) -> FormattedLengths {
+    FormattedLengths {
+        values: range.start().value()..=range.end().value(),
+        style,
+    }
+}
+
+/// Returns the combined formatted length of every numeral in `range`.
+///
+/// ## Examples
+///
+/// ```
+/// use xvii::{total_formatted_len, Roman, Style};
+///
+/// let range = Roman::new(1).unwrap()..=Roman::new(100).unwrap();
+/// assert_eq!(total_formatted_len(range, Style::Lower), 401);
+/// ```
+pub fn total_formatted_len(range: RangeInclusive<Roman>, style: Style) -> usize {
+    formatted_lengths(range, style).sum()
+}
+
+/// Iterator over the formatted lengths of a range of numerals.
+///
+/// This struct is created by the [`formatted_lengths`] function.
+#[derive(Debug, Clone)]
+pub struct FormattedLengths {
+    values: ops::RangeInclusive<u16>,
+    style: Style,
+}
+
+impl Iterator for FormattedLengths {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        let value = self.values.next()?;
+        Some(len_in(value, self.style))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.values.size_hint()
+    }
+}
+
+impl DoubleEndedIterator for FormattedLengths {
+    fn next_back(&mut self) -> Option<usize> {
+        let value = self.values.next_back()?;
+        Some(len_in(value, self.style))
+    }
+}
+
+impl ExactSizeIterator for FormattedLengths {}
+
+impl FusedIterator for FormattedLengths {}
+
+fn len_in(value: u16, style: Style) -> usize {
+    match style {
+        Style::Lower | Style::Upper => ladder::formatted_len(value),
+        // Every character of the Number Forms block is three bytes long.
+        Style::Unicode => 3 * ladder::formatted_chars(value, style),
+        Style::Clock => ladder::formatted_chars(value, style),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{formatted_lengths, total_formatted_len};
+    use crate::{Roman, Style};
+
+    #[test]
+    fn lengths_match_formatting() {
+        let range = Roman::new(1).unwrap()..=Roman::new(4999).unwrap();
+        let lengths = formatted_lengths(range.clone(), Style::Upper);
+        assert_eq!(4999, lengths.len());
+
+        for (n, len) in (1..=4999).zip(lengths) {
+            assert_eq!(Roman::new(n).unwrap().to_string().len(), len);
+        }
+        assert_eq!(
+            Some(10),
+            formatted_lengths(range.clone(), Style::Upper).next_back()
+        );
+
+        let lengths = formatted_lengths(range, Style::Clock);
+        for (n, len) in (1..=4999).zip(lengths) {
+            assert_eq!(
+                Roman::new(n)
+                    .unwrap()
+                    .format(Style::Clock)
+                    .to_string()
+                    .len(),
+                len
+            );
+        }
+    }
+
+    #[test]
+    fn formatted_len_in_every_style() {
+        for &style in &[Style::Lower, Style::Upper, Style::Unicode, Style::Clock] {
+            for n in 1..=4999 {
+                let n = Roman::new(n).unwrap();
+                assert_eq!(n.format(style).to_string().len(), n.formatted_len(style));
+            }
+        }
+    }
+
+    #[test]
+    fn max_value_for_len_is_exact() {
+        for len in 1..=10 {
+            let max = Roman::max_value_for_len(len, Style::Lower).unwrap();
+            assert!(max.to_string().len() <= len);
+
+            let longer =
+                (max.value() + 1..=4999).all(|n| Roman::new(n).unwrap().to_string().len() > len);
+            assert!(longer);
+        }
+        assert_eq!(
+            4999,
+            Roman::max_value_for_len(usize::MAX, Style::Upper)
+                .unwrap()
+                .value()
+        );
+    }
+
+    #[test]
+    fn reversed_range_is_empty() {
+        let range = Roman::new(10).unwrap()..=Roman::new(1).unwrap();
+        assert_eq!(0, total_formatted_len(range, Style::Upper));
+    }
+}
@@ -29,6 +29,48 @@
 //! ```toml
 //! xvii = { version = "...", default-features = false }
 //! ```
+//!
+//! Targets with an allocator but no `std` can enable the `alloc` feature instead, which
+//! brings back the methods returning a `String` or `Vec`, such as
+//! `Roman::to_uppercase`.
+//!
+//! # Optional features
+//!
+//! Integrations with other crates, and a few behaviours which not every user wants,
+//! are available behind cargo features, all of which are off by default:
+//!
+//! - `actix-web`: take `Roman` through [Actix Web](https://actix.rs) path and query
+//!   extractors, return it from handlers, and turn `Error` into a `400` response.
+//! - `arrayvec`: format numerals into an [`ArrayString`](https://docs.rs/arrayvec),
+//!   an owned string on the stack for targets without an allocator.
+//! - `arrow`: kernels for converting [Arrow](https://docs.rs/arrow) columns of numeral
+//!   strings to and from `UInt16Array`.
+//! - `bson`: conversions between `Roman` and [`bson::Bson`](https://docs.rs/bson), and
+//!   serde helpers for storing numerals in MongoDB documents.
+//! - `bumpalo`: format numerals into a [bumpalo](https://docs.rs/bumpalo) arena,
+//!   avoiding a heap allocation per value.
+//! - `component`: export parsing and formatting as a WebAssembly component, following
+//!   the [WIT](https://component-model.bytecodealliance.org) interface in `wit/xvii.wit`,
+//!   for use from any component-model host.
+//! - `futures`: extract numerals from an asynchronous stream of byte chunks, as a
+//!   [`Stream`](https://docs.rs/futures-core) of values and offsets.
+//! - `global-style`: set the style used by `Display` for `Roman` process-wide, for
+//!   applications which want every numeral in lowercase.
+//! - `godot`: conversions between `Roman` and Godot's `int`, plus a `RomanNumerals`
+//!   class for GDScript, via [gdext](https://godot-rust.github.io).
+//! - `heapless`: format numerals into a [`heapless::String`](https://docs.rs/heapless),
+//!   an owned string on the stack for targets without an allocator.
+//! - `num-traits`: `Bounded`, `One`, `CheckedAdd`, `CheckedSub`, `ToPrimitive` and
+//!   `FromPrimitive` from [num-traits](https://docs.rs/num-traits), for use in generic
+//!   numeric code.
+//! - `rocket`: use `Roman` as a [Rocket](https://rocket.rs) path parameter or form field.
+//! - `sea-orm`: use `Roman` as a field of a [SeaORM](https://www.sea-ql.org/SeaORM)
+//!   entity model, backed by a `SMALLINT` or `TEXT` column.
+//! - `serde`: `Serialize` and `Deserialize` for `Roman` and `Error`. Numerals serialize as
+//!   uppercase strings and deserialize from strings in either case, or as `u16` values in
+//!   formats which are not human-readable, such as bincode.
+//! - `ufmt`: `uDisplay` for `Roman` and `RomanFormatter`, for printing numerals with
+//!   [ufmt](https://docs.rs/ufmt) on microcontrollers where `core::fmt` is too large.
 #![cfg_attr(not(any(feature = "std", test)), no_std)]
 // To build docs properly, run
 // `RUSTFLAGS="--cfg docsrs" cargo +nightly doc --all-features`
@@ -40,12 +82,134 @@
     unsafe_code
 )]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(feature = "actix-web")]
+#[cfg_attr(docsrs, doc(cfg(feature = "actix-web")))]
+pub mod actix_web;
+mod alphabet;
+mod apostrophus;
+mod arith;
+#[cfg(feature = "arrayvec")]
+mod arrayvec_impl;
+#[cfg(feature = "arrow")]
+#[cfg_attr(docsrs, doc(cfg(feature = "arrow")))]
+pub mod arrow;
+mod backend;
+mod braille;
+#[cfg(feature = "bson")]
+#[cfg_attr(docsrs, doc(cfg(feature = "bson")))]
+pub mod bson;
+mod buf;
+#[cfg(feature = "bumpalo")]
+#[cfg_attr(docsrs, doc(cfg(feature = "bumpalo")))]
+pub mod bumpalo;
+pub mod calendar;
+mod classify;
+mod compare;
+#[cfg(feature = "component")]
+mod component;
+mod convert;
+mod cycle;
 mod error;
+pub mod eval;
+mod fraction;
+#[cfg(feature = "futures")]
+#[cfg_attr(docsrs, doc(cfg(feature = "futures")))]
+pub mod futures;
+mod global_style;
+#[cfg(feature = "godot")]
+#[cfg_attr(docsrs, doc(cfg(feature = "godot")))]
+pub mod godot;
+#[cfg(feature = "heapless")]
+mod heapless_impl;
+pub mod hours;
+mod label;
+mod lengths;
+#[cfg(feature = "alloc")]
+mod list;
+mod macros;
+#[cfg(feature = "alloc")]
+mod map;
+#[cfg(feature = "alloc")]
+mod normalize;
+mod nulla;
+#[cfg(feature = "num-traits")]
+mod num_traits_impl;
+mod options;
+mod parser;
+mod range;
+#[cfg(feature = "rocket")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rocket")))]
+pub mod rocket;
 mod roman;
+mod roman8;
+#[cfg(feature = "sea-orm")]
+#[cfg_attr(docsrs, doc(cfg(feature = "sea-orm")))]
+pub mod sea_orm;
+mod sequence;
+#[cfg(feature = "serde")]
+mod serde_impl;
+mod set;
+mod signed;
+mod span;
+mod ssml;
+mod style;
+mod suggest;
+mod table;
+#[cfg(feature = "ufmt")]
+mod ufmt_impl;
 mod unit;
+mod validate;
+mod vinculum;
+mod words;
 
-pub use error::Error;
-pub use roman::{Roman, RomanFormatter, Style};
+pub use alphabet::Alphabet;
+pub use apostrophus::Apostrophus;
+pub use backend::RomanBackend;
+pub use braille::Braille;
+pub use buf::BufTooSmall;
+pub use classify::{classify, is_canonical, is_valid, NumeralForm};
+pub use compare::cmp_str;
+pub use convert::{RomanStr, ToRoman};
+pub use cycle::Cycle;
+pub use error::{Error, ErrorContext};
+pub use fraction::{FractionFormatter, RomanFraction};
+#[cfg(feature = "global-style")]
+#[cfg_attr(docsrs, doc(cfg(feature = "global-style")))]
+pub use global_style::{default_style, set_default_style};
+pub use label::{label_components, LabelComponent, LabelComponents, LabelError};
+pub use lengths::{formatted_lengths, total_formatted_len, FormattedLengths};
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub use list::{parse_list, ListError};
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub use map::{MapIter, RomanMap};
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub use normalize::normalize;
+pub use nulla::{NullaFormatter, RomanOrNulla};
+pub use options::{OptionsFormatter, RomanFormatOptions, Vinculum};
+pub use parser::{Case, RomanParser, Subtraction};
+pub use range::RomanRange;
+pub use roman::{GenericRoman, Roman, RomanFormatter, Style};
+pub use roman8::Roman8;
+pub use sequence::{validate_sequence, SequenceError};
+pub use set::{RomanSet, SetIter};
+pub use signed::{RomanSigned, SignedFormatter};
+pub use span::{RomanSpan, SpanError};
+pub use ssml::Ssml;
+pub use style::ParseStyleError;
+pub use suggest::suggest;
+pub use table::{Cell, Cells, Column};
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub use validate::validate;
+pub use validate::{Issue, IssueKind};
+pub use vinculum::{Parenthesized, Roman32, Roman32Formatter, RomanLarge, RomanLargeFormatter};
+pub use words::{Language, Words};
 
 /// [`Result`](std::result::Result) with error defaulted to [`xvii::Error`](Error)
 pub type Result<T, E = Error> = core::result::Result<T, E>;
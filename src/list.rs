@@ -0,0 +1,114 @@
+use crate::{Error, Roman};
+use alloc::vec::Vec;
+use core::fmt::{self, Display};
+
+/// An error in parsing one item of a delimited list of numerals.
+///
+/// This struct is returned by [`parse_list`] and records where in the list the
+/// offending item was found.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct ListError {
+    index: usize,
+    offset: usize,
+    error: Error,
+}
+
+impl ListError {
+    /// Returns the zero-based index of the item which failed to parse.
+    pub const fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Returns the byte offset into the list at which the failed item begins,
+    /// after leading whitespace has been skipped.
+    pub const fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Returns the error produced while parsing the item.
+    pub const fn error(&self) -> Error {
+        self.error
+    }
+}
+
+impl Display for ListError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "Item {} (at byte {}): {}",
+            self.index, self.offset, self.error
+        )
+    }
+}
+
+impl core::error::Error for ListError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+/// Parses a list of numerals separated by `delimiter`.
+///
+/// Whitespace around each item is ignored, and a list consisting only of whitespace
+/// is empty. Parsing stops at the first item which is not a valid numeral (including
+/// an empty item between two delimiters); the returned [`ListError`] says which item
+/// that was and where it begins.
+///
+/// ## Examples
+///
+/// ```
+/// let values = xvii::parse_list("I, IV, IX, XII", ',').unwrap();
+/// let values: Vec<_> = values.into_iter().map(|n| n.value()).collect();
+/// assert_eq!(values, [1, 4, 9, 12]);
+///
+/// let error = xvii::parse_list("I; IV; IQ", ';').unwrap_err();
+/// assert_eq!(error.index(), 2);
+/// assert_eq!(error.offset(), 7);
+/// ```
+pub fn parse_list(s: &str, delimiter: char) -> Result<Vec<Roman>, ListError> {
+    if s.trim().is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let mut offset = 0;
+    s.split(delimiter)
+        .enumerate()
+        .map(|(index, item)| {
+            let trimmed = item.trim_start();
+            let start = offset + (item.len() - trimmed.len());
+            offset += item.len() + delimiter.len_utf8();
+
+            trimmed.trim_end().parse().map_err(|error| ListError {
+                index,
+                offset: start,
+                error,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse_list;
+    use crate::Error;
+
+    #[test]
+    fn blank_list_is_empty() {
+        assert_eq!(Ok(Vec::new()), parse_list("  ", ','));
+    }
+
+    #[test]
+    fn multibyte_delimiter() {
+        let values = parse_list("XVII·xviii", '·').unwrap();
+        assert_eq!(17, values[0].value());
+        assert_eq!(18, values[1].value());
+    }
+
+    #[test]
+    fn empty_item_is_an_error() {
+        let error = parse_list("I,,II", ',').unwrap_err();
+        assert_eq!(1, error.index());
+        assert_eq!(2, error.offset());
+        assert_eq!(Error::OutOfRange(0), error.error());
+    }
+}
@@ -0,0 +1,43 @@
+/// Creates a [`Roman`](crate::Roman) from a string literal, checked at compile time.
+///
+/// The literal is read as [`Roman::parse_bytes`](crate::Roman::parse_bytes) reads it,
+/// in a constant, so that a literal which does not parse is a compile error and one
+/// which does costs nothing at run time.
+///
+/// ## Examples
+///
+/// ```
+/// use xvii::{roman, Roman};
+///
+/// const YEAR: Roman = roman!("MCMLXXXIV");
+/// assert_eq!(YEAR.value(), 1984);
+/// assert_eq!(roman!("xvii").value(), 17);
+/// ```
+///
+/// ```compile_fail
+/// let n = xvii::roman!("MXQ");
+/// ```
+#[macro_export]
+macro_rules! roman {
+    ($s:literal) => {{
+        const ROMAN: $crate::Roman = match $crate::Roman::parse_bytes($s.as_bytes()) {
+            ::core::result::Result::Ok(n) => n,
+            ::core::result::Result::Err(_) => {
+                ::core::panic!(::core::concat!("invalid Roman numeral: ", $s))
+            }
+        };
+        ROMAN
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Roman;
+
+    #[test]
+    fn literals() {
+        assert_eq!(Roman::new(1984), Ok(roman!("MCMLXXXIV")));
+        assert_eq!(Roman::MAX, roman!("mmmmcmxcix"));
+        assert_eq!(Roman::new(5), Ok(roman!("iiiiix")));
+    }
+}
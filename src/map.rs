@@ -0,0 +1,252 @@
+use crate::{GenericRoman, Roman};
+use alloc::vec::Vec;
+use core::{
+    iter::{FromIterator, FusedIterator},
+    num::NonZeroU16,
+    ops::Index,
+    slice,
+};
+
+/// A map keyed by numerals, stored densely by value.
+///
+/// Every key has a slot in a vector indexed by its value, so lookups and insertions
+/// take constant time without hashing, and iteration visits keys in numeral order. The
+/// vector only grows as far as the largest key inserted, up to 4999 slots.
+///
+/// ## Examples
+///
+/// ```
+/// use xvii::{Roman, RomanMap};
+///
+/// let mut counts = RomanMap::new();
+/// for s in ["XII", "IV", "xii"] {
+///     let n: Roman = s.parse().unwrap();
+///     *counts.get_mut_or_insert(n, 0) += 1;
+/// }
+///
+/// let counts: Vec<_> = counts.iter().map(|(n, &count)| (n.value(), count)).collect();
+/// assert_eq!(counts, [(4, 1), (12, 2)]);
+/// ```
+#[derive(Clone, Debug)]
+pub struct RomanMap<V> {
+    slots: Vec<Option<V>>,
+    len: usize,
+}
+
+impl<V> RomanMap<V> {
+    /// Creates an empty map. No memory is allocated until the first insertion.
+    pub const fn new() -> Self {
+        RomanMap {
+            slots: Vec::new(),
+            len: 0,
+        }
+    }
+
+    /// Returns the number of entries in the map.
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the map has no entries.
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns a reference to the value stored for `key`.
+    pub fn get(&self, key: Roman) -> Option<&V> {
+        self.slots.get(index(key))?.as_ref()
+    }
+
+    /// Returns a mutable reference to the value stored for `key`.
+    pub fn get_mut(&mut self, key: Roman) -> Option<&mut V> {
+        self.slots.get_mut(index(key))?.as_mut()
+    }
+
+    /// Returns a mutable reference to the value stored for `key`, first inserting
+    /// `default` if there is none.
+    pub fn get_mut_or_insert(&mut self, key: Roman, default: V) -> &mut V {
+        let slot = slot(&mut self.slots, key);
+        if slot.is_none() {
+            self.len += 1;
+        }
+        slot.get_or_insert(default)
+    }
+
+    /// Returns `true` if the map holds a value for `key`.
+    pub fn contains_key(&self, key: Roman) -> bool {
+        self.get(key).is_some()
+    }
+
+    /// Stores `value` for `key`, returning the value previously stored, if any.
+    pub fn insert(&mut self, key: Roman, value: V) -> Option<V> {
+        let previous = slot(&mut self.slots, key).replace(value);
+        if previous.is_none() {
+            self.len += 1;
+        }
+        previous
+    }
+
+    /// Removes and returns the value stored for `key`.
+    pub fn remove(&mut self, key: Roman) -> Option<V> {
+        let previous = self.slots.get_mut(index(key))?.take();
+        if previous.is_some() {
+            self.len -= 1;
+        }
+        previous
+    }
+
+    /// Removes every entry, keeping the allocated slots.
+    pub fn clear(&mut self) {
+        self.slots.iter_mut().for_each(|slot| *slot = None);
+        self.len = 0;
+    }
+
+    /// Returns an iterator over the entries of the map in numeral order.
+    pub fn iter(&self) -> MapIter<'_, V> {
+        MapIter {
+            slots: self.slots.iter().enumerate(),
+            remaining: self.len,
+        }
+    }
+}
+
+impl<V> Default for RomanMap<V> {
+    fn default() -> Self {
+        RomanMap::new()
+    }
+}
+
+impl<V: PartialEq> PartialEq for RomanMap<V> {
+    fn eq(&self, other: &Self) -> bool {
+        // Removal leaves empty slots behind, so compare entries rather than slots.
+        self.len == other.len && self.iter().eq(other.iter())
+    }
+}
+
+impl<V: Eq> Eq for RomanMap<V> {}
+
+impl<V> Index<Roman> for RomanMap<V> {
+    type Output = V;
+
+    /// Returns the value stored for `key`.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if the map holds no value for `key`.
+    fn index(&self, key: Roman) -> &V {
+        self.get(key).expect("no entry for numeral")
+    }
+}
+
+impl<V> FromIterator<(Roman, V)> for RomanMap<V> {
+    fn from_iter<I: IntoIterator<Item = (Roman, V)>>(iter: I) -> Self {
+        let mut map = RomanMap::new();
+        map.extend(iter);
+        map
+    }
+}
+
+impl<V> Extend<(Roman, V)> for RomanMap<V> {
+    fn extend<I: IntoIterator<Item = (Roman, V)>>(&mut self, iter: I) {
+        for (key, value) in iter {
+            self.insert(key, value);
+        }
+    }
+}
+
+impl<'a, V> IntoIterator for &'a RomanMap<V> {
+    type Item = (Roman, &'a V);
+    type IntoIter = MapIter<'a, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Iterator over the entries of a [`RomanMap`], in numeral order.
+///
+/// This struct is created by the [`iter`](RomanMap::iter) method.
+#[derive(Clone, Debug)]
+pub struct MapIter<'a, V> {
+    slots: core::iter::Enumerate<slice::Iter<'a, Option<V>>>,
+    remaining: usize,
+}
+
+impl<'a, V> Iterator for MapIter<'a, V> {
+    type Item = (Roman, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (index, value) = self
+            .slots
+            .find_map(|(index, slot)| Some((index, slot.as_ref()?)))?;
+        self.remaining -= 1;
+        Some((key(index), value))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<V> ExactSizeIterator for MapIter<'_, V> {}
+
+impl<V> FusedIterator for MapIter<'_, V> {}
+
+/// Returns the slot for `key`, growing `slots` to hold it if necessary.
+fn slot<V>(slots: &mut Vec<Option<V>>, key: Roman) -> &mut Option<V> {
+    let index = index(key);
+    if slots.len() <= index {
+        slots.resize_with(index + 1, || None);
+    }
+    &mut slots[index]
+}
+
+fn index(key: Roman) -> usize {
+    usize::from(key.value()) - 1
+}
+
+fn key(index: usize) -> Roman {
+    // Slots are only created for valid keys, so the index is always below 4999.
+    GenericRoman(NonZeroU16::MIN.saturating_add(index as u16))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RomanMap;
+    use crate::Roman;
+
+    fn roman(n: u16) -> Roman {
+        Roman::new(n).unwrap()
+    }
+
+    #[test]
+    fn insert_and_remove() {
+        let mut map = RomanMap::new();
+        assert_eq!(None, map.insert(roman(4999), "max"));
+        assert_eq!(Some("max"), map.insert(roman(4999), "MAX"));
+        assert_eq!(None, map.insert(roman(1), "min"));
+        assert_eq!(2, map.len());
+
+        assert_eq!(Some("min"), map.remove(roman(1)));
+        assert_eq!(None, map.remove(roman(1)));
+        assert_eq!(None, map.remove(roman(2000)));
+        assert_eq!(1, map.len());
+        assert_eq!("MAX", map[roman(4999)]);
+
+        map.remove(roman(4999));
+        assert_eq!(RomanMap::new(), map);
+    }
+
+    #[test]
+    fn iterates_in_order() {
+        let map: RomanMap<_> = [(roman(10), 'x'), (roman(5), 'v'), (roman(1), 'i')]
+            .iter()
+            .copied()
+            .collect();
+
+        let iter = map.iter();
+        assert_eq!(3, iter.len());
+        let entries: Vec<_> = iter.map(|(n, &c)| (n.value(), c)).collect();
+        assert_eq!(vec![(1, 'i'), (5, 'v'), (10, 'x')], entries);
+    }
+}
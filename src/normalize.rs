@@ -0,0 +1,56 @@
+use crate::{Result, Roman};
+use alloc::string::String;
+
+impl Roman {
+    /// Parses a numeral in any spelling `FromStr` accepts and writes it again in
+    /// canonical form.
+    ///
+    /// Additive spellings such as `IIII` or `VIIII`, repeated digits such as `LLL`,
+    /// irregular subtraction such as `IC`, lowercase and precomposed Unicode digits
+    /// all come out as the uppercase numeral this crate would format.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use xvii::Roman;
+    ///
+    /// assert_eq!(Roman::canonicalize("VIIII").unwrap(), "IX");
+    /// assert_eq!(Roman::canonicalize("mdcccclxxxiiii").unwrap(), "MCMLXXXIV");
+    /// assert!(Roman::canonicalize("XQ").is_err());
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    pub fn canonicalize(s: &str) -> Result<String> {
+        s.parse::<Roman>().map(|n| n.to_uppercase())
+    }
+}
+
+/// Rewrites a numeral in canonical form, as described under [`Roman::canonicalize`].
+///
+/// ## Examples
+///
+/// ```
+/// assert_eq!(xvii::normalize("LLL").unwrap(), "CL");
+/// ```
+pub fn normalize(s: &str) -> Result<String> {
+    Roman::canonicalize(s)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::normalize;
+    use crate::Error;
+
+    #[test]
+    fn sloppy_forms() {
+        assert_eq!(Ok("IV".to_string()), normalize("IIII"));
+        assert_eq!(Ok("XCIX".to_string()), normalize("IC"));
+        assert_eq!(Ok("XII".to_string()), normalize("Ⅻ"));
+        assert_eq!(Ok("MMMMCMXCIX".to_string()), normalize("mmmmcmxcix"));
+    }
+
+    #[test]
+    fn invalid_input() {
+        assert_eq!(Err(Error::InvalidDigit(b' ', 1)), normalize("X I"));
+        assert_eq!(Err(Error::OutOfRange(5000)), normalize("MMMMM"));
+    }
+}
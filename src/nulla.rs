@@ -0,0 +1,146 @@
+use crate::{Error, Result, Roman, Style};
+use core::{
+    fmt::{self, Display},
+    str::FromStr,
+};
+
+/// A numeral or zero, written `N` for *nulla*.
+///
+/// Classical numerals have no zero, and [`Roman`] rejects it. Medieval computists
+/// wrote `N` (for *nulla* or *nihil*) in their tables instead, and this type follows
+/// them: zero is a distinct variant which formats as `N` and parses from `N` or
+/// `nulla` in either case.
+///
+/// ## Examples
+///
+/// ```
+/// use xvii::RomanOrNulla;
+///
+/// let zero: RomanOrNulla = "nulla".parse().unwrap();
+/// assert_eq!(zero, RomanOrNulla::Nulla);
+/// assert_eq!(zero.to_string(), "N");
+///
+/// let seventeen = RomanOrNulla::new(17).unwrap();
+/// assert_eq!(seventeen.value(), 17);
+/// assert_eq!(seventeen.to_string(), "XVII");
+/// ```
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum RomanOrNulla {
+    /// Zero.
+    Nulla,
+    /// Any other value.
+    Roman(Roman),
+}
+
+impl RomanOrNulla {
+    /// Creates a value based on a [`u16`], which may be zero.
+    ///
+    /// Fails with [`Error::OutOfRange`] for values above 4999.
+    pub const fn new(n: u16) -> Result<RomanOrNulla> {
+        match n {
+            0 => Ok(RomanOrNulla::Nulla),
+            _ => match Roman::new(n) {
+                Ok(n) => Ok(RomanOrNulla::Roman(n)),
+                Err(e) => Err(e),
+            },
+        }
+    }
+
+    /// Returns the value, which is zero for [`Nulla`](RomanOrNulla::Nulla).
+    pub const fn value(self) -> u16 {
+        match self {
+            RomanOrNulla::Nulla => 0,
+            RomanOrNulla::Roman(n) => n.value(),
+        }
+    }
+
+    /// Returns a [`NullaFormatter`] which lazily formats `self` in the given style.
+    pub const fn format(self, style: Style) -> NullaFormatter {
+        NullaFormatter { value: self, style }
+    }
+}
+
+impl From<Roman> for RomanOrNulla {
+    fn from(n: Roman) -> Self {
+        RomanOrNulla::Roman(n)
+    }
+}
+
+impl From<Option<Roman>> for RomanOrNulla {
+    fn from(n: Option<Roman>) -> Self {
+        n.map_or(RomanOrNulla::Nulla, RomanOrNulla::Roman)
+    }
+}
+
+impl From<RomanOrNulla> for Option<Roman> {
+    fn from(n: RomanOrNulla) -> Self {
+        match n {
+            RomanOrNulla::Nulla => None,
+            RomanOrNulla::Roman(n) => Some(n),
+        }
+    }
+}
+
+/// Lazy formatter for [`RomanOrNulla`] values.
+///
+/// This struct is created by the [`format`](RomanOrNulla::format) method.
+#[derive(Debug, Copy, Clone)]
+pub struct NullaFormatter {
+    value: RomanOrNulla,
+    style: Style,
+}
+
+impl Display for NullaFormatter {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match (self.value, self.style) {
+            (RomanOrNulla::Nulla, Style::Lower) => f.write_str("n"),
+            (RomanOrNulla::Nulla, Style::Upper | Style::Unicode | Style::Clock) => f.write_str("N"),
+            (RomanOrNulla::Roman(n), style) => n.format(style).fmt(f),
+        }
+    }
+}
+
+impl Display for RomanOrNulla {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let style = crate::global_style::display_style();
+
+        self.format(style).fmt(f)
+    }
+}
+
+impl FromStr for RomanOrNulla {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        if s.eq_ignore_ascii_case("n") || s.eq_ignore_ascii_case("nulla") {
+            return Ok(RomanOrNulla::Nulla);
+        }
+        s.parse().map(RomanOrNulla::Roman)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RomanOrNulla;
+    use crate::{Error, Roman, Style};
+
+    #[test]
+    fn parses_zero() {
+        for s in &["N", "n", "NULLA", "Nulla"] {
+            assert_eq!(Ok(RomanOrNulla::Nulla), s.parse());
+        }
+        assert_eq!(
+            Err(Error::InvalidDigit(b'N', 1)),
+            "XN".parse::<RomanOrNulla>()
+        );
+        assert_eq!(Err(Error::OutOfRange(0)), "".parse::<RomanOrNulla>());
+    }
+
+    #[test]
+    fn formats_zero() {
+        let zero = RomanOrNulla::new(0).unwrap();
+        assert_eq!("n", zero.format(Style::Lower).to_string());
+        assert_eq!(None, Option::<Roman>::from(zero));
+        assert!(zero < RomanOrNulla::new(1).unwrap());
+    }
+}
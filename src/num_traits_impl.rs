@@ -0,0 +1,99 @@
+use crate::Roman;
+use core::convert::TryFrom;
+use num_traits::{Bounded, CheckedAdd, CheckedSub, FromPrimitive, One, ToPrimitive};
+
+impl Bounded for Roman {
+    fn min_value() -> Self {
+        Roman::MIN
+    }
+
+    fn max_value() -> Self {
+        Roman::MAX
+    }
+}
+
+impl One for Roman {
+    fn one() -> Self {
+        Roman::MIN
+    }
+}
+
+impl CheckedAdd for Roman {
+    fn checked_add(&self, v: &Self) -> Option<Self> {
+        Roman::checked_add(*self, *v)
+    }
+}
+
+impl CheckedSub for Roman {
+    fn checked_sub(&self, v: &Self) -> Option<Self> {
+        Roman::checked_sub(*self, *v)
+    }
+}
+
+impl ToPrimitive for Roman {
+    fn to_i64(&self) -> Option<i64> {
+        Some(self.value().into())
+    }
+
+    fn to_u64(&self) -> Option<u64> {
+        Some(self.value().into())
+    }
+
+    fn to_u16(&self) -> Option<u16> {
+        Some(self.value())
+    }
+}
+
+/// Conversions succeed only for values in the range `1..=4999`.
+impl FromPrimitive for Roman {
+    fn from_i64(n: i64) -> Option<Self> {
+        u16::try_from(n).ok().and_then(Self::from_u16)
+    }
+
+    fn from_u64(n: u64) -> Option<Self> {
+        u16::try_from(n).ok().and_then(Self::from_u16)
+    }
+
+    fn from_u16(n: u16) -> Option<Self> {
+        Roman::new(n).ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Roman;
+    use num_traits::{Bounded, CheckedAdd, CheckedSub, FromPrimitive, One, ToPrimitive};
+
+    #[test]
+    fn bounds() {
+        assert_eq!(1, Roman::min_value().value());
+        assert_eq!(4999, Roman::max_value().value());
+    }
+
+    #[test]
+    fn arithmetic() {
+        let one = Roman::one();
+        assert!(one.is_one());
+        assert_eq!(
+            Some(2),
+            CheckedAdd::checked_add(&one, &one).map(Roman::value)
+        );
+        assert_eq!(None, CheckedAdd::checked_add(&Roman::max_value(), &one));
+        assert_eq!(None, CheckedSub::checked_sub(&one, &one));
+    }
+
+    #[test]
+    fn from_primitive_checks_range() {
+        assert_eq!(Some(17), Roman::from_i32(17).map(Roman::value));
+        assert_eq!(None, Roman::from_i64(-17));
+        assert_eq!(None, Roman::from_u16(0));
+        assert_eq!(None, Roman::from_f64(5000.0));
+    }
+
+    #[test]
+    fn to_primitive() {
+        let roman = Roman::new(1984).unwrap();
+        assert_eq!(Some(1984.0), roman.to_f64());
+        assert_eq!(None, roman.to_u8());
+    }
+}
@@ -0,0 +1,378 @@
+use crate::{
+    vinculum::{DOUBLE_OVERLINE, MACRON},
+    GenericRoman, RomanBackend,
+};
+use core::fmt::{self, Alignment, Display, Write};
+
+/// How a [`RomanFormatOptions`] marks digits multiplied by a thousand.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum Vinculum {
+    /// An overline on every digit, as [`Roman32`](crate::Roman32) writes them, with a
+    /// double overline for the millions. E.g.: `V̄`.
+    Overline,
+    /// Parentheses around the thousands, and a second pair around the millions, as
+    /// [`Roman32::parenthesized`](crate::Roman32::parenthesized) writes them. E.g.:
+    /// `(V)`.
+    Parentheses,
+}
+
+/// Options for formatting a numeral, combined in one place.
+///
+/// [`Style`](crate::Style) picks one of a few fixed ways of writing a numeral; this
+/// builder lets each choice be made separately. New options start out uppercase,
+/// subtractive and unseparated, with overlines for the thousands, which is how
+/// `Display` writes a [`RomanLarge`](crate::RomanLarge). Each method returns a copy
+/// of the options with one setting changed, so that they can be kept in a `const`.
+///
+/// ## Examples
+///
+/// ```
+/// use xvii::{Roman, Roman32, RomanFormatOptions, Vinculum};
+///
+/// const INSCRIPTION: RomanFormatOptions = RomanFormatOptions::new().separator(Some('·'));
+/// let n = Roman::new(1984).unwrap();
+/// assert_eq!(INSCRIPTION.format(n).to_string(), "M·CM·LXXX·IV");
+///
+/// let tally = RomanFormatOptions::new().lowercase(true).additive(true);
+/// assert_eq!(tally.format(n).to_string(), "mdcccclxxxiiii");
+///
+/// let plain = RomanFormatOptions::new().vinculum(Vinculum::Parentheses);
+/// assert_eq!(plain.format(Roman32::new(11_017).unwrap()).to_string(), "(XI)XVII");
+/// ```
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct RomanFormatOptions {
+    lowercase: bool,
+    clock: bool,
+    additive: bool,
+    separator: Option<char>,
+    vinculum: Vinculum,
+}
+
+impl RomanFormatOptions {
+    /// Creates options which format numerals as `Display` does.
+    pub const fn new() -> Self {
+        RomanFormatOptions {
+            lowercase: false,
+            clock: false,
+            additive: false,
+            separator: None,
+            vinculum: Vinculum::Overline,
+        }
+    }
+
+    /// Sets whether digits are written in lowercase.
+    pub const fn lowercase(self, lowercase: bool) -> Self {
+        RomanFormatOptions { lowercase, ..self }
+    }
+
+    /// Sets whether 4 is written `IIII`, as on a clock face, while 9 is still `IX`.
+    pub const fn clock(self, clock: bool) -> Self {
+        RomanFormatOptions { clock, ..self }
+    }
+
+    /// Sets whether numerals are written without any subtractive pairs, so that 4 is
+    /// `IIII` and 9 is `VIIII`.
+    pub const fn additive(self, additive: bool) -> Self {
+        RomanFormatOptions { additive, ..self }
+    }
+
+    /// Sets a character to write between decimal places, as in the inscription
+    /// `M·CM·LXXX·IV`.
+    ///
+    /// A [`RomanParser`](crate::RomanParser) reads such numerals back when its
+    /// [`separators`](crate::RomanParser::separators) are enabled, so long as the
+    /// separator is one it knows.
+    pub const fn separator(self, separator: Option<char>) -> Self {
+        RomanFormatOptions { separator, ..self }
+    }
+
+    /// Sets how digits multiplied by a thousand are marked.
+    pub const fn vinculum(self, vinculum: Vinculum) -> Self {
+        RomanFormatOptions { vinculum, ..self }
+    }
+
+    /// Returns an [`OptionsFormatter`] which lazily formats `n` with these options.
+    pub fn format<T>(self, n: GenericRoman<T>) -> OptionsFormatter
+    where
+        T: RomanBackend + Into<u64>,
+    {
+        OptionsFormatter {
+            options: self,
+            value: n.get().into(),
+        }
+    }
+}
+
+impl Default for RomanFormatOptions {
+    fn default() -> Self {
+        RomanFormatOptions::new()
+    }
+}
+
+/// Lazy formatter for numerals written with [`RomanFormatOptions`].
+///
+/// This struct is created by the [`format`](RomanFormatOptions::format) method. Like
+/// [`RomanFormatter`](crate::RomanFormatter), it honors width, fill, alignment and
+/// precision.
+#[derive(Debug, Copy, Clone)]
+pub struct OptionsFormatter {
+    options: RomanFormatOptions,
+    value: u64,
+}
+
+/// The digits for one, five and ten in each decimal place below the thousands.
+const PLACES: [(char, char, char); 3] = [('C', 'D', 'M'), ('X', 'L', 'C'), ('I', 'V', 'X')];
+
+impl OptionsFormatter {
+    /// Writes a single digit with the case and marks of its level.
+    fn write_digit(&self, f: &mut dyn Write, c: char, level: u32) -> fmt::Result {
+        match self.options.lowercase {
+            true => f.write_char(c.to_ascii_lowercase())?,
+            false => f.write_char(c)?,
+        }
+        match (self.options.vinculum, level) {
+            (_, 0) | (Vinculum::Parentheses, _) => Ok(()),
+            (Vinculum::Overline, 1) => f.write_char(MACRON),
+            (Vinculum::Overline, _) => f.write_char(DOUBLE_OVERLINE),
+        }
+    }
+
+    /// Writes a single decimal place, whose digit is `n`.
+    fn write_place(
+        &self,
+        f: &mut dyn Write,
+        n: u64,
+        (one, five, ten): (char, char, char),
+        level: u32,
+    ) -> fmt::Result {
+        let ones = match n {
+            9 if !self.options.additive => {
+                self.write_digit(f, one, level)?;
+                return self.write_digit(f, ten, level);
+            }
+            4 if !(self.options.additive || self.options.clock && one == 'I') => {
+                self.write_digit(f, one, level)?;
+                return self.write_digit(f, five, level);
+            }
+            5..=9 => {
+                self.write_digit(f, five, level)?;
+                n - 5
+            }
+            _ => n,
+        };
+
+        for _ in 0..ones {
+            self.write_digit(f, one, level)?;
+        }
+        Ok(())
+    }
+
+    /// Writes the numeral, without any padding.
+    fn write(&self, f: &mut dyn Write) -> fmt::Result {
+        let top = match self.value {
+            0..=4999 => 0,
+            5000..=4_999_999 => 1,
+            _ => 2,
+        };
+        let mut first = true;
+
+        for level in (0..=top).rev() {
+            let scale = 1000_u64.pow(level);
+            let part = match level {
+                _ if level == top => self.value / scale,
+                _ => self.value / scale % 1000,
+            };
+            if part == 0 {
+                continue;
+            }
+
+            let parens = match self.options.vinculum {
+                Vinculum::Parentheses => level,
+                Vinculum::Overline => 0,
+            };
+            if let (Some(separator), false) = (self.options.separator, first) {
+                f.write_char(separator)?;
+            }
+            for _ in 0..parens {
+                f.write_char('(')?;
+            }
+
+            // Only the top level has thousands, which are all `M`.
+            for _ in 0..part / 1000 {
+                self.write_digit(f, 'M', level)?;
+            }
+            let mut written = part >= 1000;
+
+            for (i, &place) in PLACES.iter().enumerate() {
+                let n = part / 10_u64.pow(2 - i as u32) % 10;
+                if n == 0 {
+                    continue;
+                }
+                if let (Some(separator), true) = (self.options.separator, written) {
+                    f.write_char(separator)?;
+                }
+                self.write_place(f, n, place, level)?;
+                written = true;
+            }
+
+            for _ in 0..parens {
+                f.write_char(')')?;
+            }
+            first = false;
+        }
+
+        Ok(())
+    }
+}
+
+impl Display for OptionsFormatter {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if f.width().is_none() && f.precision().is_none() {
+            return self.write(f);
+        }
+
+        // The numeral has no bound on its length to format it on the stack, as
+        // `Formatter::pad` would need, so it is measured first and padded by hand.
+        let mut counter = Truncate {
+            inner: None,
+            remaining: f.precision().unwrap_or(usize::MAX),
+        };
+        self.write(&mut counter)?;
+        let len = f.precision().unwrap_or(usize::MAX) - counter.remaining;
+
+        let padding = f.width().unwrap_or(0).saturating_sub(len);
+        let (before, after) = match f.align() {
+            Some(Alignment::Right) => (padding, 0),
+            Some(Alignment::Center) => (padding / 2, padding - padding / 2),
+            Some(Alignment::Left) | None => (0, padding),
+        };
+
+        let fill = f.fill();
+        for _ in 0..before {
+            f.write_char(fill)?;
+        }
+        self.write(&mut Truncate {
+            inner: Some(&mut *f),
+            remaining: len,
+        })?;
+        for _ in 0..after {
+            f.write_char(fill)?;
+        }
+        Ok(())
+    }
+}
+
+/// Passes on at most `remaining` characters to `inner`, counting those written.
+struct Truncate<'a> {
+    inner: Option<&'a mut dyn Write>,
+    remaining: usize,
+}
+
+impl Write for Truncate<'_> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        s.chars().try_for_each(|c| self.write_char(c))
+    }
+
+    fn write_char(&mut self, c: char) -> fmt::Result {
+        if self.remaining == 0 {
+            return Ok(());
+        }
+        self.remaining -= 1;
+        match &mut self.inner {
+            Some(inner) => inner.write_char(c),
+            None => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{RomanFormatOptions, Vinculum};
+    use crate::{Roman, Roman32, RomanLarge, RomanParser, Style};
+
+    #[test]
+    fn default_matches_display() {
+        let options = RomanFormatOptions::new();
+        for n in 1..=4999 {
+            let n = Roman::new(n).unwrap();
+            assert_eq!(n.to_string(), options.format(n).to_string());
+        }
+        for &n in &[5000, 6_001, 1_234_567, 4_999_999] {
+            let n = Roman32::new(n).unwrap();
+            assert_eq!(n.to_string(), options.format(n).to_string());
+        }
+        let n = RomanLarge::new(2_000_000_017).unwrap();
+        assert_eq!(n.to_string(), options.format(n).to_string());
+    }
+
+    #[test]
+    fn clock_matches_style() {
+        let options = RomanFormatOptions::new().clock(true);
+        for n in 1..=4999 {
+            let n = Roman::new(n).unwrap();
+            assert_eq!(
+                n.format(Style::Clock).to_string(),
+                options.format(n).to_string()
+            );
+        }
+    }
+
+    #[test]
+    fn padding() {
+        let options = RomanFormatOptions::new();
+        let n = Roman::new(17).unwrap();
+        assert_eq!(format!("{:>8}", n), format!("{:>8}", options.format(n)));
+        assert_eq!("XVII    ", format!("{:8}", options.format(n)));
+        assert_eq!("**XVII**", format!("{:*^8}", options.format(n)));
+        assert_eq!("XV", format!("{:.2}", options.format(n)));
+        assert_eq!("XVII", format!("{:2}", options.format(n)));
+
+        let n = Roman32::new(5000).unwrap();
+        assert_eq!("  V\u{304}", format!("{:>4}", options.format(n)));
+    }
+
+    #[test]
+    fn additive() {
+        let options = RomanFormatOptions::new().additive(true);
+        let format = |n| options.format(Roman::new(n).unwrap()).to_string();
+        assert_eq!("IIII", format(4));
+        assert_eq!("VIIII", format(9));
+        assert_eq!("MDCCCCLXXXXVIIII", format(1999));
+    }
+
+    #[test]
+    fn separators_round_trip() {
+        let options = RomanFormatOptions::new().separator(Some(' '));
+        let parser = RomanParser::new().separators(true);
+        assert_eq!(
+            "M CM LXXX IV",
+            options.format(Roman::new(1984).unwrap()).to_string()
+        );
+        for n in 1..=4999 {
+            let n = Roman::new(n).unwrap();
+            assert_eq!(Ok(n), parser.parse(&options.format(n).to_string()));
+        }
+    }
+
+    #[test]
+    fn parentheses() {
+        let options = RomanFormatOptions::new()
+            .vinculum(Vinculum::Parentheses)
+            .lowercase(true);
+        let n = Roman32::new(11_017).unwrap();
+        assert_eq!(
+            n.parenthesized(Style::Lower).to_string(),
+            options.format(n).to_string()
+        );
+        assert_eq!(
+            "((v))(iii)iv",
+            options
+                .format(RomanLarge::new(5_003_004).unwrap())
+                .to_string()
+        );
+        assert_eq!(
+            "(x·i)·x·vii",
+            options.separator(Some('·')).format(n).to_string()
+        );
+    }
+}
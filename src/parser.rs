@@ -0,0 +1,708 @@
+use crate::{
+    unit::{self, RomanUnitIterator},
+    vinculum, Error, Result, Roman, Roman32,
+};
+use core::{convert::TryFrom, str::CharIndices};
+
+/// Which case a [`RomanParser`] accepts digits in.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum Case {
+    /// Either case, mixed freely, as `FromStr` does.
+    Any,
+    /// Uppercase digits only.
+    Upper,
+    /// Lowercase digits only.
+    Lower,
+    /// Either case, so long as every digit is in the same one. The first digit
+    /// decides, and the first in the other case is reported as invalid.
+    Uniform,
+}
+
+/// Which subtractive pairs a [`RomanParser`] accepts.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum Subtraction {
+    /// Any smaller digits before a larger one, as `FromStr` does, so that `IC` is 99
+    /// and `IIX` is 8.
+    Any,
+    /// Only the six classical pairs: `IV`, `IX`, `XL`, `XC`, `CD` and `CM`, each
+    /// written as a single digit would be, so that neither `IXC` nor `IXI` is read.
+    Classical,
+    /// No subtraction at all, so that 4 must be written `IIII`.
+    None,
+}
+
+/// A parser for numerals with configurable rules.
+///
+/// A new parser accepts exactly what `FromStr` for [`Roman`] accepts. Each method
+/// returns a copy of the parser with one rule changed, so that a parser can be built
+/// up in a single expression and kept in a `const`.
+///
+/// ## Examples
+///
+/// ```
+/// use xvii::{Case, Error, RomanParser, Subtraction};
+///
+/// const STRICT: RomanParser = RomanParser::new()
+///     .case(Case::Upper)
+///     .max_repeats(3)
+///     .subtraction(Subtraction::Classical)
+///     .max_value(3999);
+///
+/// assert_eq!(STRICT.parse("MCMLXXXIV").unwrap().value(), 1984);
+/// assert_eq!(STRICT.parse("mcmlxxxiv"), Err(Error::InvalidDigit(b'm', 0)));
+/// assert_eq!(STRICT.parse("IIII"), Err(Error::TooManyRepeats('I')));
+/// assert_eq!(STRICT.parse("IC"), Err(Error::InvalidPair('I', 'C')));
+/// assert_eq!(STRICT.parse("MMMM"), Err(Error::TooManyRepeats('M')));
+///
+/// let lenient = RomanParser::new().trim(true);
+/// assert_eq!(lenient.parse(" XVII\n").unwrap().value(), 17);
+/// ```
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct RomanParser {
+    case: Case,
+    subtraction: Subtraction,
+    max_repeats: Option<u16>,
+    max_value: Option<u32>,
+    trim: bool,
+    final_j: bool,
+    u_for_v: bool,
+    separators: bool,
+}
+
+impl RomanParser {
+    /// Creates a parser which accepts what `FromStr` accepts.
+    pub const fn new() -> Self {
+        RomanParser {
+            case: Case::Any,
+            subtraction: Subtraction::Any,
+            max_repeats: None,
+            max_value: None,
+            trim: false,
+            final_j: false,
+            u_for_v: false,
+            separators: false,
+        }
+    }
+
+    /// Creates a parser for the orthography of manuscripts and early printed books,
+    /// with [`final_j`](RomanParser::final_j) and [`u_for_v`](RomanParser::u_for_v)
+    /// both set.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use xvii::RomanParser;
+    ///
+    /// let parser = RomanParser::medieval();
+    /// assert_eq!(parser.parse("xuij").unwrap().value(), 17);
+    /// assert_eq!(parser.parse("Luii").unwrap().value(), 57);
+    /// ```
+    pub const fn medieval() -> Self {
+        RomanParser::new().final_j(true).u_for_v(true)
+    }
+
+    /// Sets which case digits must be written in.
+    pub const fn case(self, case: Case) -> Self {
+        RomanParser { case, ..self }
+    }
+
+    /// Sets which subtractive pairs are accepted.
+    ///
+    /// A pair which is not allowed is reported with [`Error::InvalidPair`]. With
+    /// [`Subtraction::Classical`], so is a classical pair after a digit worth less
+    /// than ten times its smaller digit, as in `VIX`, or before a digit worth as much
+    /// as its smaller digit, as in `IXC` or `IXI`.
+    pub const fn subtraction(self, subtraction: Subtraction) -> Self {
+        RomanParser {
+            subtraction,
+            ..self
+        }
+    }
+
+    /// Sets how many times in a row the same digit may be written: 3 for strict
+    /// classical numerals, or 4 to admit `IIII` and `MMMM`. By default there is no
+    /// limit, as for tallies.
+    ///
+    /// The first digit past the limit is reported with [`Error::TooManyRepeats`].
+    pub const fn max_repeats(self, max_repeats: u16) -> Self {
+        RomanParser {
+            max_repeats: Some(max_repeats),
+            ..self
+        }
+    }
+
+    /// Sets the largest value accepted, beyond which parsing fails with
+    /// [`Error::OutOfRange`], as for [`Roman::new_bounded`]. Use 3999 to hold to
+    /// classical numerals, or a bound such as 2100 for plausible years.
+    ///
+    /// Values beyond the range of the numeral being parsed are rejected whatever the
+    /// bound.
+    pub const fn max_value(self, max_value: u32) -> Self {
+        RomanParser {
+            max_value: Some(max_value),
+            ..self
+        }
+    }
+
+    /// Sets whether whitespace around the numeral is ignored.
+    pub const fn trim(self, trim: bool) -> Self {
+        RomanParser { trim, ..self }
+    }
+
+    /// Sets whether a final `j` is read as `i`, as in the medieval `xiij` for 13.
+    ///
+    /// A `j` anywhere else is still invalid.
+    pub const fn final_j(self, final_j: bool) -> Self {
+        RomanParser { final_j, ..self }
+    }
+
+    /// Sets whether `u` is read as `v`, as in `xuij` for 17.
+    pub const fn u_for_v(self, u_for_v: bool) -> Self {
+        RomanParser { u_for_v, ..self }
+    }
+
+    /// Sets whether separators within a numeral are ignored, as in the inscription
+    /// `M·CM·LXXX·IV`.
+    ///
+    /// The separators are spaces, including no-break and thin spaces, and the
+    /// interpuncts `·` (U+00B7) and `⸱` (U+2E31).
+    pub const fn separators(self, separators: bool) -> Self {
+        RomanParser { separators, ..self }
+    }
+
+    /// Parses a numeral according to the rules of this parser.
+    pub fn parse(&self, s: &str) -> Result<Roman> {
+        let value = u16::try_from(self.sum(s, 0)?).map_err(|_| Error::Overflow)?;
+        match self.max_value {
+            Some(max) => Roman::new_bounded(value, u16::try_from(max).unwrap_or(u16::MAX)),
+            None => Roman::new(value),
+        }
+    }
+
+    /// Parses a numeral which may use the vinculum, according to the rules of this
+    /// parser.
+    ///
+    /// Overlined digits are read as described under [`Roman32`], and must all come
+    /// before ordinary ones. Each run of digits with the same overlining is checked
+    /// as a numeral of its own, so that `ĪĪĪMMM` repeats no digit more than three
+    /// times.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use xvii::RomanParser;
+    ///
+    /// let parser = RomanParser::new().max_repeats(3);
+    /// assert_eq!(parser.parse32("X\u{304}V\u{304}II").unwrap().value(), 15_002);
+    /// assert_eq!(parser.parse32("I\u{305}I\u{305}I\u{305}MMM").unwrap().value(), 6000);
+    /// ```
+    pub fn parse32(&self, s: &str) -> Result<Roman32> {
+        let value = u32::try_from(self.sum(s, 1)?).map_err(|_| Error::Overflow)?;
+        match self.max_value {
+            Some(max) if value > max => Err(u16::try_from(value)
+                .map(Error::OutOfRange)
+                .unwrap_or(Error::Overflow)),
+            _ => Roman32::new(value),
+        }
+    }
+
+    /// Sums the digits of a numeral, each multiplied by a thousand for every
+    /// overline on it up to `max_level`.
+    ///
+    /// This is also how `FromStr` reads [`Roman32`] and
+    /// [`RomanLarge`](crate::RomanLarge), with a new parser.
+    pub(crate) fn sum(&self, s: &str, max_level: u32) -> Result<u64> {
+        let (start, s) = match self.trim {
+            true => (s.len() - s.trim_start().len(), s.trim()),
+            false => (0, s),
+        };
+        let digits = Rules {
+            parser: self,
+            digits: Digits {
+                parser: self,
+                chars: s.char_indices(),
+                start,
+                max_level,
+                pending: ('\0', 0, 0, &[]),
+            },
+            previous: None,
+            run: 0,
+            upper: None,
+            classical: Classical::new(),
+        };
+
+        unit::sum_units(RomanUnitIterator::from_digits(digits))
+    }
+}
+
+impl Default for RomanParser {
+    fn default() -> Self {
+        RomanParser::new()
+    }
+}
+
+/// A digit of a numeral.
+#[derive(Copy, Clone)]
+struct Digit {
+    /// The character the digit was read from.
+    c: char,
+    value: u16,
+    /// The number of overlines on the digit.
+    level: u32,
+    /// The byte offset of the character in the numeral.
+    offset: usize,
+}
+
+/// Iterates the digits of a numeral.
+struct Digits<'a> {
+    parser: &'a RomanParser,
+    chars: CharIndices<'a>,
+    /// The offset of the characters within the untrimmed numeral.
+    start: usize,
+    max_level: u32,
+    pending: (char, u32, usize, &'static [u16]),
+}
+
+impl Digits<'_> {
+    /// Returns the next character which is not a separator to be skipped, along with
+    /// its offset.
+    fn next_char(&mut self) -> Option<(usize, char)> {
+        let separators = self.parser.separators;
+        let (offset, c) = self
+            .chars
+            .find(|&(_, c)| !(separators && is_separator(c)))?;
+        Some((self.start + offset, c))
+    }
+
+    /// Whether nothing but separators to be skipped remains.
+    fn at_end(&self) -> bool {
+        let separators = self.parser.separators;
+        self.chars
+            .as_str()
+            .chars()
+            .all(|c| separators && is_separator(c))
+    }
+
+    /// Consumes the overlines following a digit, returning its level.
+    ///
+    /// Overlines are left in place when none are allowed, to be rejected as
+    /// characters of their own.
+    fn level(&mut self) -> u32 {
+        let mut level = 0;
+        if self.max_level == 0 {
+            return level;
+        }
+
+        while let Some(marks) = self
+            .chars
+            .as_str()
+            .chars()
+            .next()
+            .and_then(vinculum::mark_level)
+        {
+            self.chars.next();
+            level += marks;
+        }
+        level
+    }
+}
+
+impl Iterator for Digits<'_> {
+    type Item = Result<Digit>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (c, level, offset, pending) = self.pending;
+        if let Some((&value, rest)) = pending.split_first() {
+            self.pending = (c, level, offset, rest);
+            return Some(Ok(Digit {
+                c,
+                value,
+                level,
+                offset,
+            }));
+        }
+
+        let (offset, c) = self.next_char()?;
+        let level = self.level();
+        if level > self.max_level {
+            return Some(Err(invalid(c, offset)));
+        }
+
+        let digit = |value| Digit {
+            c,
+            value,
+            level,
+            offset,
+        };
+        if self.parser.final_j && (c == 'j' || c == 'J') && self.at_end() {
+            return Some(Ok(digit(1)));
+        }
+        if let Ok(u) = u8::try_from(c) {
+            if self.parser.u_for_v && u.eq_ignore_ascii_case(&b'u') {
+                return Some(Ok(digit(5)));
+            }
+            if u.is_ascii() {
+                return Some(unit::digit_at(u, offset).map(digit));
+            }
+        }
+
+        match unit::number_form(c) {
+            Some((&value, rest)) => {
+                self.pending = (c, level, offset, rest);
+                Some(Ok(digit(value)))
+            }
+            None => Some(Err(Error::InvalidChar(c, offset))),
+        }
+    }
+}
+
+/// Checks each digit against the rules of a parser, passing on its value.
+struct Rules<'a, I> {
+    parser: &'a RomanParser,
+    digits: I,
+    previous: Option<Digit>,
+    run: u16,
+    /// Whether the first digit was uppercase, once it has been read.
+    upper: Option<bool>,
+    classical: Classical<Digit>,
+}
+
+impl<I: Iterator<Item = Result<Digit>>> Iterator for Rules<'_, I> {
+    type Item = Result<u64>;
+
+    fn next(&mut self) -> Option<Result<u64>> {
+        let digit = self.digits.next()?;
+        Some(digit.and_then(|digit| self.check(digit)))
+    }
+}
+
+impl<I> Rules<'_, I> {
+    /// Checks a digit against the rules, returning its value at its level.
+    fn check(&mut self, digit: Digit) -> Result<u64> {
+        let Digit {
+            c,
+            value,
+            level,
+            offset,
+        } = digit;
+
+        // Each level is checked as a numeral of its own, and they must descend.
+        let previous = match self.previous {
+            Some(previous) if previous.level > level => {
+                self.classical = Classical::new();
+                None
+            }
+            Some(previous) if previous.level < level => return Err(invalid(c, offset)),
+            previous => previous,
+        };
+
+        let upper = *self.upper.get_or_insert(is_upper(c));
+        let cased = match self.parser.case {
+            Case::Any => true,
+            Case::Upper => is_upper(c),
+            Case::Lower => !is_upper(c),
+            Case::Uniform => is_upper(c) == upper,
+        };
+        if !cased {
+            return Err(invalid(c, offset));
+        }
+
+        match self.parser.subtraction {
+            Subtraction::Any => {}
+            Subtraction::Classical => match self.classical.next(value, digit) {
+                Some(Breach::InvalidPair(smaller, larger)) => {
+                    return Err(Error::InvalidPair(smaller.c, larger.c))
+                }
+                Some(Breach::PairAfterSmaller(smaller)) => {
+                    return Err(Error::InvalidPair(smaller.c, c))
+                }
+                Some(Breach::AfterPair(smaller, larger)) => {
+                    return Err(Error::InvalidPair(smaller.c, larger.c))
+                }
+                None => {}
+            },
+            Subtraction::None => match previous {
+                Some(previous) if previous.value < value => {
+                    return Err(Error::InvalidPair(previous.c, c))
+                }
+                _ => {}
+            },
+        }
+
+        self.run = match previous {
+            Some(previous) if previous.value == value => self.run + 1,
+            _ => 1,
+        };
+        if let Some(max) = self.parser.max_repeats {
+            if self.run > max {
+                return Err(Error::TooManyRepeats(c));
+            }
+        }
+
+        self.previous = Some(digit);
+        Ok(u64::from(value) * 1000_u64.pow(level))
+    }
+}
+
+fn is_separator(c: char) -> bool {
+    matches!(c, ' ' | '\u{a0}' | '\u{2009}' | '\u{202f}' | '·' | '⸱')
+}
+
+/// Whether `smaller` before `larger` is one of the six classical subtractive pairs.
+pub(crate) fn is_classical_pair(smaller: u16, larger: u16) -> bool {
+    matches!(smaller, 1 | 10 | 100) && (larger == smaller * 5 || larger == smaller * 10)
+}
+
+/// A way in which a digit breaks the rules of classical subtraction, in terms of the
+/// digits passed to [`Classical::next`].
+pub(crate) enum Breach<T> {
+    /// A smaller digit before a larger one other than the six classical pairs, or
+    /// after the same digit, as in `IIX`.
+    InvalidPair(T, T),
+    /// A classical pair, starting at the given digit, after a digit worth less than
+    /// ten times its smaller one, as in `VIX`.
+    PairAfterSmaller(T),
+    /// A digit worth as much as the smaller digit of the classical pair before it, as
+    /// in `IXI`.
+    AfterPair(T, T),
+}
+
+/// Checks digits one at a time against the rules of classical subtraction, for
+/// [`RomanParser`] and [`validate`](crate::validate) alike.
+///
+/// Each digit is passed along with whatever the caller needs to report it, which is
+/// handed back in any [`Breach`].
+pub(crate) struct Classical<T> {
+    before: Option<(u16, T)>,
+    previous: Option<(u16, T)>,
+    run: u16,
+    /// The smaller digit of the last pair, and both digits, until a digit follows it.
+    pair: Option<(u16, T, T)>,
+}
+
+impl<T: Copy> Classical<T> {
+    pub(crate) const fn new() -> Self {
+        Classical {
+            before: None,
+            previous: None,
+            run: 0,
+            pair: None,
+        }
+    }
+
+    /// Checks the next digit, of the given value.
+    pub(crate) fn next(&mut self, value: u16, digit: T) -> Option<Breach<T>> {
+        let before = self.before;
+        let previous = self.previous;
+        let previous_run = self.run;
+        self.run = match previous {
+            Some((p, _)) if p == value => self.run + 1,
+            _ => 1,
+        };
+        self.before = previous;
+        self.previous = Some((value, digit));
+
+        match previous {
+            Some((p, smaller)) if p < value => {
+                self.pair = Some((p, smaller, digit));
+                if !is_classical_pair(p, value) || previous_run > 1 {
+                    Some(Breach::InvalidPair(smaller, digit))
+                } else if matches!(before, Some((b, _)) if b < p * 10) {
+                    Some(Breach::PairAfterSmaller(smaller))
+                } else {
+                    None
+                }
+            }
+            _ => match self.pair.take() {
+                Some((p, smaller, larger)) if value >= p => {
+                    Some(Breach::AfterPair(smaller, larger))
+                }
+                _ => None,
+            },
+        }
+    }
+}
+
+fn is_upper(c: char) -> bool {
+    match c {
+        '\u{2160}'..='\u{216f}' => true,
+        '\u{2170}'..='\u{217f}' => false,
+        _ => c.is_ascii_uppercase(),
+    }
+}
+
+fn invalid(c: char, offset: usize) -> Error {
+    match c {
+        '\0'..='\x7f' => Error::InvalidDigit(c as u8, offset),
+        _ => Error::InvalidChar(c, offset),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Case, RomanParser, Subtraction};
+    use crate::{Error, Roman, Roman32};
+
+    #[test]
+    fn default_matches_from_str() {
+        let parser = RomanParser::new();
+        for s in &["MCMLXXXIV", "iiiiix", "IC", "Ⅻ", "", "XQ", " X"] {
+            assert_eq!(s.parse::<Roman>(), parser.parse(s));
+        }
+    }
+
+    #[test]
+    fn case() {
+        let upper = RomanParser::new().case(Case::Upper);
+        assert_eq!(Ok(12), upper.parse("Ⅻ").map(Roman::value));
+        assert_eq!(Err(Error::InvalidChar('ⅻ', 0)), upper.parse("ⅻ"));
+
+        let lower = RomanParser::new().case(Case::Lower);
+        assert_eq!(Ok(14), lower.parse("xiv").map(Roman::value));
+        assert_eq!(Err(Error::InvalidDigit(b'V', 2)), lower.parse("xiV"));
+        let uniform = RomanParser::new().case(Case::Uniform);
+        assert_eq!(Ok(17), uniform.parse("xvii").map(Roman::value));
+        assert_eq!(Ok(17), uniform.parse("XVII").map(Roman::value));
+        assert_eq!(Ok(13), uniform.parse("ⅩⅢ").map(Roman::value));
+        assert_eq!(Err(Error::InvalidDigit(b'v', 1)), uniform.parse("XvIi"));
+        assert_eq!(Err(Error::InvalidChar('ⅲ', 1)), uniform.parse("Xⅲ"));
+    }
+
+    #[test]
+    fn subtraction() {
+        let classical = RomanParser::new().subtraction(Subtraction::Classical);
+        for s in &["IV", "IX", "XL", "XC", "CD", "CM", "MCMXCIX"] {
+            assert_eq!(s.parse::<Roman>(), classical.parse(s));
+        }
+        assert_eq!(Err(Error::InvalidPair('X', 'M')), classical.parse("XM"));
+        assert_eq!(Err(Error::InvalidPair('I', 'X')), classical.parse("IIX"));
+        assert_eq!(Err(Error::InvalidPair('V', 'X')), classical.parse("VX"));
+        assert_eq!(Err(Error::InvalidPair('i', 'c')), classical.parse("ic"));
+        assert_eq!(Err(Error::InvalidPair('X', 'C')), classical.parse("IXC"));
+        assert_eq!(Err(Error::InvalidPair('C', 'M')), classical.parse("XCM"));
+        assert_eq!(Err(Error::InvalidPair('C', 'M')), classical.parse("CMD"));
+        assert_eq!(Err(Error::InvalidPair('I', 'X')), classical.parse("IXIX"));
+        assert_eq!(Err(Error::InvalidPair('X', 'L')), classical.parse("IXL"));
+        assert_eq!(Err(Error::InvalidPair('I', 'X')), classical.parse("VIX"));
+        assert_eq!(Err(Error::InvalidPair('X', 'L')), classical.parse("XLX"));
+        for n in 1..=4999 {
+            let n = Roman::new(n).unwrap();
+            assert_eq!(Ok(n), classical.parse(&n.to_string()));
+        }
+
+        let additive = RomanParser::new().subtraction(Subtraction::None);
+        assert_eq!(Ok(4), additive.parse("IIII").map(Roman::value));
+        assert_eq!(Err(Error::InvalidPair('I', 'V')), additive.parse("IV"));
+    }
+
+    #[test]
+    fn repeats_and_bounds() {
+        let parser = RomanParser::new().max_repeats(3).max_value(2100);
+        assert_eq!(Ok(2100), parser.parse("MMC").map(Roman::value));
+        assert_eq!(Err(Error::OutOfRange(2101)), parser.parse("MMCI"));
+        assert_eq!(
+            Err(Error::OutOfRange(5000)),
+            RomanParser::new().max_value(70_000).parse("MMMMM")
+        );
+        assert_eq!(Err(Error::TooManyRepeats('I')), parser.parse("XIIII"));
+        assert_eq!(Err(Error::TooManyRepeats('Ⅹ')), parser.parse("ⅩⅩⅩⅩ"));
+        assert_eq!(
+            Ok(4),
+            RomanParser::new()
+                .max_repeats(4)
+                .parse("IIII")
+                .map(Roman::value)
+        );
+        assert_eq!(Ok(8), parser.parse("VIII").map(Roman::value));
+    }
+
+    #[test]
+    fn final_j() {
+        let parser = RomanParser::new().final_j(true);
+        assert_eq!(Ok(13), parser.parse("xiij").map(Roman::value));
+        assert_eq!(Ok(7), parser.parse("VIJ").map(Roman::value));
+        assert_eq!(Ok(1), parser.parse("j").map(Roman::value));
+        assert_eq!(Err(Error::InvalidDigit(b'j', 1)), parser.parse("xjj"));
+        assert_eq!(
+            Err(Error::InvalidDigit(b'j', 3)),
+            RomanParser::new().parse("xiij")
+        );
+
+        let uniform = parser.case(Case::Uniform);
+        assert_eq!(Err(Error::InvalidDigit(b'J', 3)), uniform.parse("xiiJ"));
+    }
+
+    #[test]
+    fn u_for_v() {
+        let parser = RomanParser::new().u_for_v(true);
+        assert_eq!(Ok(4), parser.parse("iu").map(Roman::value));
+        assert_eq!(Ok(5), parser.parse("U").map(Roman::value));
+        assert_eq!(
+            Err(Error::InvalidDigit(b'u', 1)),
+            RomanParser::new().parse("iu")
+        );
+        assert_eq!(
+            Err(Error::InvalidDigit(b'U', 1)),
+            parser.case(Case::Lower).parse("xU")
+        );
+    }
+
+    #[test]
+    fn vinculum() {
+        let parser = RomanParser::new();
+        assert_eq!(Ok(5000), parser.parse32("V\u{304}").map(Roman32::value));
+        assert_eq!(Ok(4_999_999), parser.parse32("M\u{304}M\u{304}M\u{304}M\u{304}C\u{304}M\u{304}X\u{304}C\u{304}I\u{304}X\u{304}CMXCIX").map(Roman32::value));
+        assert_eq!(
+            Err(Error::InvalidDigit(b'V', 1)),
+            parser.parse32("IV\u{304}")
+        );
+        assert_eq!(
+            Err(Error::InvalidDigit(b'V', 0)),
+            parser.parse32("V\u{33f}")
+        );
+        assert_eq!(
+            Err(Error::InvalidChar('\u{304}', 1)),
+            parser.parse("V\u{304}")
+        );
+
+        let classical = parser.subtraction(Subtraction::Classical).max_value(10_000);
+        assert_eq!(
+            Ok(9000),
+            classical.parse32("I\u{304}X\u{304}").map(Roman32::value)
+        );
+        assert_eq!(
+            Err(Error::InvalidPair('I', 'C')),
+            classical.parse32("I\u{304}C\u{304}")
+        );
+        assert_eq!(
+            Err(Error::OutOfRange(10_001)),
+            classical.parse32("X\u{304}I")
+        );
+    }
+
+    #[test]
+    fn separators() {
+        let parser = RomanParser::new().separators(true);
+        assert_eq!(Ok(1984), parser.parse("M·CM·LXXX·IV").map(Roman::value));
+        assert_eq!(
+            Ok(1984),
+            parser.parse("M CM\u{2009}LXXX⸱IV").map(Roman::value)
+        );
+        assert_eq!(
+            Ok(13),
+            parser.final_j(true).parse("xiij·").map(Roman::value)
+        );
+        assert_eq!(Err(Error::InvalidDigit(b'-', 1)), parser.parse("M-CM"));
+        assert_eq!(
+            Err(Error::InvalidChar('·', 1)),
+            RomanParser::new().parse("M·CM")
+        );
+    }
+
+    #[test]
+    fn trim() {
+        let parser = RomanParser::new().trim(true);
+        assert_eq!(Ok(10), parser.parse("\tX ").map(Roman::value));
+        assert_eq!(Err(Error::InvalidDigit(b' ', 1)), parser.parse("X I"));
+    }
+}
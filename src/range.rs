@@ -0,0 +1,186 @@
+use crate::Roman;
+use core::iter::FusedIterator;
+
+impl Roman {
+    /// Returns an iterator over every numeral, from `I` to `MMMMCMXCIX`, in order.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use xvii::Roman;
+    ///
+    /// assert_eq!(Roman::all().len(), 4999);
+    /// assert_eq!(Roman::all().next(), Some(Roman::MIN));
+    /// assert_eq!(Roman::all().next_back(), Some(Roman::MAX));
+    /// ```
+    pub const fn all() -> RomanRange {
+        RomanRange::new(Roman::MIN, Roman::MAX)
+    }
+
+    /// Returns a [`RomanRange`] over the numerals from this one up to `end`, both
+    /// included.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use xvii::Roman;
+    ///
+    /// let appendices = Roman::new(1).unwrap().to(Roman::new(4).unwrap());
+    /// let labels: Vec<_> = appendices.map(|n| n.to_string()).collect();
+    /// assert_eq!(labels, ["I", "II", "III", "IV"]);
+    /// ```
+    pub const fn to(self, end: Roman) -> RomanRange {
+        RomanRange::new(self, end)
+    }
+
+    /// Returns the numeral after this one, or `None` for [`Roman::MAX`].
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use xvii::Roman;
+    ///
+    /// assert_eq!(Roman::new(17).unwrap().succ().unwrap().value(), 18);
+    /// assert_eq!(Roman::MAX.succ(), None);
+    /// ```
+    pub const fn succ(self) -> Option<Roman> {
+        match Roman::new(self.value() + 1) {
+            Ok(n) => Some(n),
+            Err(_) => None,
+        }
+    }
+
+    /// Returns the numeral before this one, or `None` for [`Roman::MIN`].
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use xvii::Roman;
+    ///
+    /// assert_eq!(Roman::new(17).unwrap().pred().unwrap().value(), 16);
+    /// assert_eq!(Roman::MIN.pred(), None);
+    /// ```
+    pub const fn pred(self) -> Option<Roman> {
+        match Roman::new(self.value() - 1) {
+            Ok(n) => Some(n),
+            Err(_) => None,
+        }
+    }
+}
+
+/// An iterator over the numerals from one to another, both included.
+///
+/// This struct is created by [`RomanRange::new`], or by the [`to`](Roman::to) and
+/// [`all`](Roman::all) methods. A range whose start is after its end is empty.
+///
+/// ## Examples
+///
+/// ```
+/// use xvii::{Roman, RomanRange};
+///
+/// let range = RomanRange::new(Roman::new(8).unwrap(), Roman::new(12).unwrap());
+/// assert_eq!(range.len(), 5);
+/// assert_eq!(range.rev().next().unwrap().to_string(), "XII");
+/// ```
+#[derive(Debug, Copy, Clone)]
+pub struct RomanRange {
+    // Empty once `front` passes `back`, which may then be zero.
+    front: u16,
+    back: u16,
+}
+
+impl RomanRange {
+    /// Creates a range over the numerals from `start` to `end`, both included.
+    pub const fn new(start: Roman, end: Roman) -> RomanRange {
+        RomanRange {
+            front: start.value(),
+            back: end.value(),
+        }
+    }
+}
+
+impl Iterator for RomanRange {
+    type Item = Roman;
+
+    fn next(&mut self) -> Option<Roman> {
+        if self.front > self.back {
+            return None;
+        }
+        let n = Roman::new_const(self.front);
+        self.front += 1;
+        Some(n)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = usize::from(self.back.saturating_sub(self.front))
+            + usize::from(self.front <= self.back);
+        (len, Some(len))
+    }
+}
+
+impl DoubleEndedIterator for RomanRange {
+    fn next_back(&mut self) -> Option<Roman> {
+        if self.front > self.back {
+            return None;
+        }
+        let n = Roman::new_const(self.back);
+        self.back -= 1;
+        Some(n)
+    }
+}
+
+impl ExactSizeIterator for RomanRange {}
+
+impl FusedIterator for RomanRange {}
+
+#[cfg(test)]
+mod tests {
+    use super::RomanRange;
+    use crate::Roman;
+
+    fn roman(n: u16) -> Roman {
+        Roman::new(n).unwrap()
+    }
+
+    #[test]
+    fn all() {
+        let mut expected = 1..=4999;
+        assert!(Roman::all().map(Roman::value).eq(expected.clone()));
+        assert!(Roman::all()
+            .rev()
+            .map(Roman::value)
+            .eq(expected.by_ref().rev()));
+        assert_eq!(4999, Roman::all().rev().len());
+    }
+
+    #[test]
+    fn succ_and_pred() {
+        let mut n = Roman::MIN;
+        while let Some(next) = n.succ() {
+            assert_eq!(Some(n), next.pred());
+            n = next;
+        }
+        assert_eq!(Roman::MAX, n);
+    }
+
+    #[test]
+    fn range() {
+        assert!(roman(3).to(roman(7)).map(Roman::value).eq(3..=7));
+        assert!(roman(3)
+            .to(roman(7))
+            .rev()
+            .map(Roman::value)
+            .eq((3..=7).rev()));
+        assert_eq!(1, roman(7).to(roman(7)).len());
+        assert_eq!(0, roman(8).to(roman(7)).len());
+        assert_eq!(None, RomanRange::new(roman(8), roman(7)).next());
+
+        let mut range = roman(1).to(roman(3));
+        assert_eq!(Some(roman(1)), range.next());
+        assert_eq!(Some(roman(3)), range.next_back());
+        assert_eq!(1, range.len());
+        assert_eq!(Some(roman(2)), range.next_back());
+        assert_eq!(None, range.next());
+        assert_eq!(None, range.next_back());
+    }
+}
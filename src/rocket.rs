@@ -0,0 +1,95 @@
+//! [Rocket](https://rocket.rs) support.
+//!
+//! With this feature enabled, `Roman` can be used as a dynamic path parameter and
+//! as a form or query field. A segment or field which is not a valid numeral is
+//! rejected with `422 Unprocessable Entity`.
+//!
+//! ```rust
+//! use rocket::{get, routes};
+//! use xvii::Roman;
+//!
+//! #[get("/chapter/<number>")]
+//! fn chapter(number: Roman) -> String {
+//!     format!("Chapter {} of the book", number)
+//! }
+//!
+//! #[get("/chapters?<from>&<to>")]
+//! fn chapters(from: Roman, to: Option<Roman>) -> String {
+//!     format!("{} to {:?}", from, to)
+//! }
+//!
+//! let rocket = rocket::build().mount("/", routes![chapter, chapters]);
+//! ```
+
+use crate::{Error, Roman};
+use rocket::{
+    form::{self, FromFormField, ValueField},
+    request::FromParam,
+};
+
+impl<'a> FromParam<'a> for Roman {
+    type Error = Error;
+
+    fn from_param(param: &'a str) -> Result<Self, Error> {
+        param.parse()
+    }
+}
+
+impl<'v> FromFormField<'v> for Roman {
+    fn from_value(field: ValueField<'v>) -> form::Result<'v, Self> {
+        field
+            .value
+            .parse()
+            .map_err(|e: Error| form::Error::validation(e.to_string()).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Roman;
+    use rocket::{get, http::Status, local::blocking::Client, routes};
+
+    #[get("/chapter/<number>")]
+    fn chapter(number: Roman) -> String {
+        number.value().to_string()
+    }
+
+    #[get("/chapters?<from>")]
+    fn chapters(from: Roman) -> String {
+        from.value().to_string()
+    }
+
+    fn client() -> Client {
+        Client::tracked(rocket::build().mount("/", routes![chapter, chapters])).unwrap()
+    }
+
+    #[test]
+    fn param() {
+        let client = client();
+        assert_eq!(
+            "14",
+            client.get("/chapter/XIV").dispatch().into_string().unwrap()
+        );
+        assert_eq!(
+            Status::UnprocessableEntity,
+            client.get("/chapter/XQV").dispatch().status()
+        );
+    }
+
+    #[test]
+    fn form_field() {
+        let client = client();
+        assert_eq!(
+            "9",
+            client
+                .get("/chapters?from=ix")
+                .dispatch()
+                .into_string()
+                .unwrap()
+        );
+        assert_eq!(
+            Status::UnprocessableEntity,
+            client.get("/chapters?from=0").dispatch().status()
+        );
+    }
+}
@@ -1,32 +1,270 @@
-mod ladder;
+pub(crate) mod ladder;
 
-use crate::{unit::RomanUnitIterator, Error, Result};
+use crate::{
+    backend::{sealed::Sealed, RomanBackend},
+    unit, Error, Result,
+};
+#[cfg(feature = "alloc")]
+use alloc::string::String;
 use core::{
-    fmt::{self, Display},
+    convert::TryFrom,
+    fmt::{self, Debug, Display},
     num::NonZeroU16,
-    str::FromStr,
+    str::{self, FromStr},
 };
 
+/// A Roman numeral, generic over the integer type which backs it.
+///
+/// The backing type decides the range and notation of the numeral; see
+/// [`RomanBackend`]. Most code will use one of the aliases, [`Roman8`](crate::Roman8),
+/// [`Roman`], [`Roman32`](crate::Roman32) or [`RomanLarge`](crate::RomanLarge), each
+/// of which has its own constructors and formatters. The methods and traits implemented for
+/// every backend are there for code which should work with any of them.
+///
+/// ## Examples
+///
+/// ```
+/// use xvii::{GenericRoman, RomanBackend};
+///
+/// fn parse_any<T: RomanBackend>(s: &str) -> Option<T> {
+///     s.parse::<GenericRoman<T>>().ok().map(GenericRoman::get)
+/// }
+///
+/// assert_eq!(parse_any::<u16>("XVII"), Some(17));
+/// assert_eq!(parse_any::<u32>("V\u{304}"), Some(5000));
+/// assert_eq!(parse_any::<u16>("V\u{304}"), None);
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct GenericRoman<T: RomanBackend>(pub(crate) T::NonZero);
+
 /// A Roman numeral.
 ///
-/// This struct stores the value of a numeral as an [`NonZeroU16`] but provides
+/// This type stores the value of a numeral as an [`NonZeroU16`] but provides
 /// for Roman-style formatting.
-#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
-pub struct Roman(NonZeroU16);
+///
+/// `Display` writes uppercase numerals, or lowercase ones with the alternate flag.
+///
+/// ## Examples
+///
+/// ```
+/// use xvii::Roman;
+///
+/// let n = Roman::new(1984).unwrap();
+/// assert_eq!(format!("{}", n), "MCMLXXXIV");
+/// assert_eq!(format!("{:#}", n), "mcmlxxxiv");
+/// ```
+pub type Roman = GenericRoman<u16>;
+
+impl<T: RomanBackend> GenericRoman<T> {
+    /// Creates a numeral from a value of the backing type, failing for values
+    /// outside the backend's range just as its own constructor would.
+    pub fn from_value(n: T) -> Result<Self> {
+        T::new(n)
+    }
+
+    /// Returns the value of this numeral as the backing type.
+    pub fn get(self) -> T {
+        T::get(self)
+    }
+}
 
 impl Roman {
+    /// The length in bytes of the longest numeral in any [`Style`], `ⅯⅯⅯⅯⅮⅭⅭⅭⅬⅩⅩⅩⅧ`,
+    /// which is enough to hold any `Roman` formatted into a buffer.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use std::io::Write;
+    /// use xvii::{Roman, Style};
+    ///
+    /// let mut buf = [0; Roman::MAX_FORMATTED_LEN];
+    /// let n = Roman::new(4888).unwrap();
+    /// write!(&mut buf[..], "{}", n.format(Style::Unicode)).unwrap();
+    /// ```
+    pub const MAX_FORMATTED_LEN: usize = ladder::MAX_UNICODE_LEN;
+
+    /// The smallest numeral, `I`.
+    pub const MIN: Roman = GenericRoman(NonZeroU16::MIN);
+
+    /// The largest numeral, `MMMMCMXCIX`.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use xvii::Roman;
+    ///
+    /// assert_eq!(Roman::MIN.value(), 1);
+    /// assert_eq!(Roman::MAX.to_string(), "MMMMCMXCIX");
+    /// assert_eq!(Roman::new(6000).unwrap_or(Roman::MAX), Roman::MAX);
+    /// ```
+    pub const MAX: Roman = Roman::new_const(4999);
+
     /// Creates a `Roman` value based on a [`u16`].
     ///
-    /// This function will return `None` if the value supplied is outside the
-    /// acceptable range of `1..=4999`, because numbers outside that range
-    /// cannot be appropriately formatted using the seven standard numerals.
-    pub fn new(n: u16) -> Result<Roman> {
+    /// This function will return [`Error::OutOfRange`] if the value supplied is
+    /// outside the acceptable range of `1..=4999`, because numbers outside that
+    /// range cannot be appropriately formatted using the seven standard numerals.
+    /// Use [`try_new`](Roman::try_new) to tell zero apart from values that are too
+    /// large.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use xvii::{Error, Roman};
+    ///
+    /// const XII: Result<Roman, Error> = Roman::new(12);
+    /// assert_eq!(XII.map(Roman::value), Ok(12));
+    /// ```
+    pub const fn new(n: u16) -> Result<Roman> {
         match NonZeroU16::new(n) {
-            Some(n) if n.get() <= 4999 => Ok(Roman(n)),
+            Some(n) if n.get() <= 4999 => Ok(GenericRoman(n)),
             _ => Err(Error::OutOfRange(n)),
         }
     }
 
+    /// Creates a `Roman` value based on a [`u16`], panicking if it is outside the
+    /// range `1..=4999`.
+    ///
+    /// This is meant for constants, where an out-of-range value is caught at
+    /// compile time.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use xvii::Roman;
+    ///
+    /// const CHAPTER_MAX: Roman = Roman::new_const(12);
+    /// assert_eq!(CHAPTER_MAX.to_string(), "XII");
+    /// ```
+    ///
+    /// ```compile_fail
+    /// const NOTHING: xvii::Roman = xvii::Roman::new_const(0);
+    /// ```
+    pub const fn new_const(n: u16) -> Roman {
+        match Roman::new(n) {
+            Ok(n) => n,
+            Err(_) => panic!("value out of range for a Roman numeral"),
+        }
+    }
+
+    /// Creates a `Roman` value based on a [`u16`], clamping it to the range
+    /// `1..=4999`.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use xvii::Roman;
+    ///
+    /// assert_eq!(Roman::new_saturating(17).value(), 17);
+    /// assert_eq!(Roman::new_saturating(0), Roman::MIN);
+    /// assert_eq!(Roman::new_saturating(9000), Roman::MAX);
+    /// ```
+    pub const fn new_saturating(n: u16) -> Roman {
+        match Roman::new(n) {
+            Ok(n) => n,
+            Err(_) if n == 0 => Roman::MIN,
+            Err(_) => Roman::MAX,
+        }
+    }
+
+    /// Creates a `Roman` value based on a [`u16`], reporting which bound was violated.
+    ///
+    /// Unlike [`new`](Roman::new), this returns [`Error::Zero`] for zero, reserving
+    /// [`Error::OutOfRange`] for values above 4999.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use xvii::{Error, Roman};
+    ///
+    /// assert_eq!(Roman::try_new(17).map(Roman::value), Ok(17));
+    /// assert_eq!(Roman::try_new(0), Err(Error::Zero));
+    /// assert_eq!(Roman::try_new(5000), Err(Error::OutOfRange(5000)));
+    /// ```
+    pub const fn try_new(n: u16) -> Result<Roman> {
+        match n {
+            0 => Err(Error::Zero),
+            _ => Roman::new(n),
+        }
+    }
+
+    /// Creates a `Roman` value based on a [`u16`] no greater than `max`.
+    ///
+    /// This is [`new`](Roman::new) with a tighter bound, such as 3999 for numerals
+    /// which never repeat `M` four times, or the current year for dates. Values above
+    /// `max` fail with [`Error::OutOfRange`], as do values above 4999 whatever `max`
+    /// is. [`RomanParser::max_value`](crate::RomanParser::max_value) applies the same
+    /// bound when parsing.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use xvii::{Error, Roman};
+    ///
+    /// assert_eq!(Roman::new_bounded(2024, 2100).map(Roman::value), Ok(2024));
+    /// assert_eq!(Roman::new_bounded(2101, 2100), Err(Error::OutOfRange(2101)));
+    /// ```
+    pub const fn new_bounded(n: u16, max: u16) -> Result<Roman> {
+        match n {
+            _ if n > max => Err(Error::OutOfRange(n)),
+            _ => Roman::new(n),
+        }
+    }
+
+    /// Parses the numeral at the start of `s`, returning it along with the number of
+    /// bytes it takes up.
+    ///
+    /// The numeral runs for as long as there are digits, so that the rest of `s` can
+    /// be handed on to another parser. Fails as parsing the numeral alone would, and
+    /// if `s` does not begin with a digit, as parsing its first character would.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use xvii::Roman;
+    ///
+    /// let (n, len) = Roman::parse_prefix("XIV. Of Kings").unwrap();
+    /// assert_eq!((n.value(), len), (14, 3));
+    /// assert!(Roman::parse_prefix("4. Of Kings").is_err());
+    /// ```
+    pub fn parse_prefix(s: &str) -> Result<(Roman, usize)> {
+        let len = s
+            .char_indices()
+            .find(|&(_, c)| !is_digit(c))
+            .map_or(s.len(), |(i, _)| i);
+        let numeral = match (len, s.chars().next()) {
+            (0, Some(c)) => &s[..c.len_utf8()],
+            _ => &s[..len],
+        };
+
+        numeral.parse().map(|n| (n, len))
+    }
+
+    /// Parses a numeral from bytes, without first checking that they are UTF-8.
+    ///
+    /// Every ordinary numeral is ASCII, so this accepts the same as `FromStr` except
+    /// for the precomposed Unicode numerals. Any other byte, including one which
+    /// begins a multibyte character, fails with [`Error::InvalidDigit`].
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use xvii::{Error, Roman};
+    ///
+    /// assert_eq!(Roman::parse_bytes(b"MMXXIV").unwrap().value(), 2024);
+    /// assert_eq!(Roman::parse_bytes(b"X\xffI"), Err(Error::InvalidDigit(0xff, 1)));
+    /// ```
+    ///
+    /// Unlike `FromStr`, this can be evaluated at compile time, which is how the
+    /// [`roman!`](crate::roman) macro checks its literals.
+    pub const fn parse_bytes(bytes: &[u8]) -> Result<Roman> {
+        match unit::sum_bytes(bytes) {
+            Ok(n) => Roman::new(n),
+            Err(e) => Err(e),
+        }
+    }
+
     /// Formats a [`Roman`] value as an uppercase Roman numeral.
     ///
     /// ## Examples
@@ -35,8 +273,8 @@ impl Roman {
     /// use xvii::Roman;
     /// assert_eq!(Roman::new(42).unwrap().to_uppercase(), "XLII");
     /// ```
-    #[cfg(feature = "std")]
-    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
     pub fn to_uppercase(self) -> String {
         let mut current = self.0.get();
         let mut buf = String::new();
@@ -59,8 +297,8 @@ impl Roman {
     /// use xvii::Roman;
     /// assert_eq!(Roman::new(42).unwrap().to_lowercase(), "xlii");
     /// ```
-    #[cfg(feature = "std")]
-    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
     pub fn to_lowercase(self) -> String {
         let mut current = self.0.get();
         let mut buf = String::new();
@@ -93,6 +331,51 @@ impl Roman {
         }
     }
 
+    /// Formats a [`Roman`] value into a fixed-size byte array, returning the array and
+    /// the length of the numeral within it.
+    ///
+    /// Unlike the other formatting methods, this can be evaluated at compile time, for
+    /// building static tables of labels. The bytes beyond the numeral are zero.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use xvii::{Roman, Style};
+    ///
+    /// const LABEL: ([u8; 39], usize) = match Roman::new(17) {
+    ///     Ok(roman) => roman.to_array(Style::Lower),
+    ///     Err(_) => panic!("out of range"),
+    /// };
+    /// assert_eq!(&LABEL.0[..LABEL.1], b"xvii");
+    /// ```
+    pub const fn to_array(self, style: Style) -> ([u8; Roman::MAX_FORMATTED_LEN], usize) {
+        let mut buf = [0; Roman::MAX_FORMATTED_LEN];
+        let mut len = 0;
+        let mut current = self.0.get();
+        let tail = match style {
+            Style::Unicode => ladder::unicode_tail(current),
+            Style::Lower | Style::Upper | Style::Clock => 0,
+        };
+        current -= tail;
+        let mut i = 0;
+
+        while i < ladder::VALUES.len() {
+            let entry = &ladder::VALUES[i];
+            while current >= entry.value {
+                len = push_bytes(&mut buf, len, entry.digits(style).as_bytes());
+                current -= entry.value;
+            }
+            i += 1;
+        }
+
+        let len = push_bytes(
+            &mut buf,
+            len,
+            ladder::NUMBER_FORMS[tail as usize].as_bytes(),
+        );
+        (buf, len)
+    }
+
     /// Returns value of this `Roman` numeral.
     ///
     /// ## Examples
@@ -118,55 +401,153 @@ impl Roman {
     }
 }
 
-/// Style of formatting — lowercase or uppercase.
-#[derive(Debug, Copy, Clone)]
+/// Copies `bytes` into `buf` at `len`, returning the new length.
+const fn push_bytes(buf: &mut [u8], mut len: usize, bytes: &[u8]) -> usize {
+    let mut i = 0;
+    while i < bytes.len() {
+        buf[len] = bytes[i];
+        len += 1;
+        i += 1;
+    }
+    len
+}
+
+/// Style of formatting — lowercase, uppercase or Unicode numeral characters.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
 pub enum Style {
     /// Lowercase formatting. E.g.: `xvii`.
     Lower,
     /// Uppercase formatting. E.g.: `XVII`.
     Upper,
+    /// Formatting with the characters of the Unicode Number Forms block. E.g.: `ⅩⅦ`.
+    ///
+    /// The units are written as one precomposed character, as are the last two places
+    /// when they make eleven or twelve, so that 12 is `Ⅻ` and 1984 is `ⅯⅭⅯⅬⅩⅩⅩⅣ`.
+    Unicode,
+    /// Uppercase formatting as on a clock face, where 4 is written `IIII` but 9 is
+    /// still `IX`. E.g.: `XIIII`.
+    Clock,
 }
 
 /// Lazy roman formatter.
 ///
-/// This struct is created by [`format`](Roman::format) method.
+/// This struct is created by [`format`](Roman::format) method. Like `Roman` itself, it
+/// honors width, fill and alignment, so that `format!("{:>8}", ...)` lines up in a
+/// table.
 #[derive(Debug, Copy, Clone)]
 pub struct RomanFormatter {
     style: Style,
     value: NonZeroU16,
 }
 
+impl RomanFormatter {
+    /// Formats the numeral on the stack, for writers which need it all at once.
+    pub(crate) fn with_str<R>(&self, f: impl FnOnce(&str) -> R) -> R {
+        let (buf, len) = GenericRoman(self.value).to_array(self.style);
+        match str::from_utf8(&buf[..len]) {
+            Ok(s) => f(s),
+            Err(_) => unreachable!("numerals are valid UTF-8"),
+        }
+    }
+}
+
 impl Display for RomanFormatter {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if f.width().is_some() || f.precision().is_some() {
+            // Padding needs the whole numeral up front.
+            return self.with_str(|s| f.pad(s));
+        }
+
         let mut current = self.value.get();
+        let tail = match self.style {
+            Style::Unicode => ladder::unicode_tail(current),
+            Style::Lower | Style::Upper | Style::Clock => 0,
+        };
+        current -= tail;
 
         for entry in ladder::VALUES {
             while current >= entry.value {
-                match self.style {
-                    Style::Lower => f.write_str(entry.lower)?,
-                    Style::Upper => f.write_str(entry.upper)?,
-                }
+                f.write_str(entry.digits(self.style))?;
                 current -= entry.value;
             }
         }
 
-        Ok(())
+        f.write_str(ladder::NUMBER_FORMS[usize::from(tail)])
+    }
+}
+
+fn is_digit(c: char) -> bool {
+    match u8::try_from(c) {
+        Ok(u) => unit::to_digit(u).is_some(),
+        Err(_) => unit::number_form(c).is_some(),
+    }
+}
+
+impl Sealed for u16 {}
+
+impl RomanBackend for u16 {
+    type NonZero = NonZeroU16;
+
+    const MAX: u16 = 4999;
+    const NAME: &'static str = "Roman";
+
+    fn new(n: u16) -> Result<Roman> {
+        Roman::new(n)
+    }
+
+    fn get(n: Roman) -> u16 {
+        n.value()
+    }
+
+    fn fmt(n: Roman, style: Style, f: &mut fmt::Formatter) -> fmt::Result {
+        Display::fmt(&n.format(style), f)
+    }
+
+    fn parse(s: &str) -> Result<Roman> {
+        Roman::new(unit::sum(s)?)
     }
 }
 
-impl FromStr for Roman {
+impl<T: RomanBackend> FromStr for GenericRoman<T> {
     type Err = Error;
 
     fn from_str(s: &str) -> Result<Self> {
-        let sum = RomanUnitIterator::new(s)
-            .try_fold(0, |acc, r| r?.checked_add(acc).ok_or(Error::Overflow))?;
-        Roman::new(sum)
+        T::parse(s)
+    }
+}
+
+impl<T: RomanBackend> TryFrom<&str> for GenericRoman<T> {
+    type Error = Error;
+
+    fn try_from(s: &str) -> Result<Self> {
+        T::parse(s)
+    }
+}
+
+impl TryFrom<&[u8]> for Roman {
+    type Error = Error;
+
+    /// Parses a numeral as [`Roman::parse_bytes`] does.
+    fn try_from(bytes: &[u8]) -> Result<Self> {
+        Roman::parse_bytes(bytes)
+    }
+}
+
+impl<T: RomanBackend> Display for GenericRoman<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let style = crate::global_style::display_style();
+
+        // `{:#}` is shorthand for lowercase, whatever the default.
+        let style = if f.alternate() { Style::Lower } else { style };
+
+        T::fmt(*self, style, f)
     }
 }
 
-impl Display for Roman {
+// Written by hand so that each alias keeps its own name, as in `Roman(17)`.
+impl<T: RomanBackend> Debug for GenericRoman<T> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        self.format(Style::Upper).fmt(f)
+        f.debug_tuple(T::NAME).field(&self.0).finish()
     }
 }
 
@@ -174,7 +555,7 @@ impl Display for Roman {
 mod tests {
     use crate::Error;
 
-    use super::Roman;
+    use super::{Roman, Style};
 
     #[test]
     fn mcmlxxxiv_equals_1984() {
@@ -199,6 +580,8 @@ mod tests {
     #[test]
     fn max_value_equals_4999() {
         assert_eq!("MMMMCMXCIX", Roman::new(4999).unwrap().to_string());
+        assert_eq!(Roman::new(4999), Ok(Roman::MAX));
+        assert_eq!(Roman::new(1), Ok(Roman::MIN));
     }
 
     #[test]
@@ -207,6 +590,85 @@ mod tests {
         assert_eq!(4999, result.value());
     }
 
+    #[test]
+    fn to_array_matches_display() {
+        for n in 1..=4999 {
+            let roman = Roman::new(n).unwrap();
+            let (buf, len) = roman.to_array(Style::Upper);
+            assert_eq!(roman.to_string().as_bytes(), &buf[..len]);
+        }
+    }
+
+    #[test]
+    fn unicode() {
+        let format = |n| Roman::new(n).unwrap().format(Style::Unicode).to_string();
+        assert_eq!("Ⅻ", format(12));
+        assert_eq!("ⅭⅪ", format(111));
+        assert_eq!("ⅩⅩⅩⅠ", format(31));
+        assert_eq!("ⅯⅯⅯⅯⅮⅭⅭⅭⅬⅩⅩⅩⅧ", format(4888));
+
+        for n in 1..=4999 {
+            let roman = Roman::new(n).unwrap();
+            let (buf, len) = roman.to_array(Style::Unicode);
+            assert_eq!(
+                roman.format(Style::Unicode).to_string().as_bytes(),
+                &buf[..len]
+            );
+            assert_eq!(Ok(roman), format(n).parse());
+        }
+    }
+
+    #[test]
+    fn alternate_is_lowercase() {
+        let n = Roman::new(2024).unwrap();
+        assert_eq!("mmxxiv", format!("{:#}", n));
+        assert_eq!("MMXXIV", format!("{}", n));
+        assert_eq!(
+            "v\u{304}",
+            format!("{:#}", crate::Roman32::new(5000).unwrap())
+        );
+    }
+
+    #[test]
+    fn padding() {
+        let n = Roman::new(17).unwrap();
+        assert_eq!("    XVII", format!("{:>8}", n));
+        assert_eq!("XVII    ", format!("{:<8}", n));
+        assert_eq!("*xvii*", format!("{:*^6}", n.format(Style::Lower)));
+        assert_eq!(
+            "  Ⅻ",
+            format!("{:>3}", Roman::new(12).unwrap().format(Style::Unicode))
+        );
+        assert_eq!("xvii", format!("{:#2}", n));
+        assert_eq!("XV", format!("{:.2}", n));
+    }
+
+    #[test]
+    fn max_formatted_len() {
+        let styles = [Style::Lower, Style::Upper, Style::Unicode, Style::Clock];
+        let longest = (1..=4999)
+            .flat_map(|n| styles.iter().map(move |&style| (n, style)))
+            .map(|(n, style)| Roman::new(n).unwrap().format(style).to_string().len())
+            .max();
+        assert_eq!(Some(Roman::MAX_FORMATTED_LEN), longest);
+    }
+
+    #[test]
+    fn clock() {
+        let format = |n| Roman::new(n).unwrap().format(Style::Clock).to_string();
+        assert_eq!("IIII", format(4));
+        assert_eq!("IX", format(9));
+        assert_eq!("XLIIII", format(44));
+        assert_eq!("MCMXCIIII", format(1994));
+
+        for n in 1..=4999 {
+            let roman = Roman::new(n).unwrap();
+            let (buf, len) = roman.to_array(Style::Clock);
+            assert_eq!(format(n).as_bytes(), &buf[..len]);
+            assert_eq!(Ok(roman), format(n).parse());
+        }
+    }
+
     #[test]
     fn overflow() {
         assert_eq!(
@@ -234,4 +696,66 @@ mod tests {
             "MMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMMM".parse::<Roman>()
         );
     }
+    #[test]
+    fn parse_prefix() {
+        assert_eq!(
+            Ok((Roman::new(1984).unwrap(), 9)),
+            Roman::parse_prefix("MCMLXXXIV")
+        );
+        assert_eq!(Ok((Roman::new(12).unwrap(), 3)), Roman::parse_prefix("Ⅻ)"));
+        assert_eq!(Ok((Roman::new(2).unwrap(), 2)), Roman::parse_prefix("ii.3"));
+        assert_eq!(
+            Err(Error::InvalidDigit(b'(', 0)),
+            Roman::parse_prefix("(ii)")
+        );
+        assert_eq!(Err(Error::InvalidChar('é', 0)), Roman::parse_prefix("é"));
+        assert_eq!(Err(Error::OutOfRange(0)), Roman::parse_prefix(""));
+        assert_eq!(
+            Err(Error::OutOfRange(5000)),
+            Roman::parse_prefix("MMMMM and")
+        );
+    }
+
+    #[test]
+    fn parse_bytes_matches_from_str() {
+        for n in (1..=4999).step_by(7) {
+            let n = Roman::new(n).unwrap();
+            let (buf, len) = n.to_array(Style::Lower);
+            assert_eq!(Ok(n), Roman::parse_bytes(&buf[..len]));
+        }
+        for s in &["IIII", "iiiiix", "IC", "MDCCCCLXXXIIII", "LLL", "XIIX"] {
+            assert_eq!(s.parse(), Roman::parse_bytes(s.as_bytes()));
+        }
+        assert_eq!(Err(Error::OutOfRange(0)), Roman::parse_bytes(b""));
+        assert_eq!(
+            Err(Error::InvalidDigit(0xe2, 0)),
+            Roman::parse_bytes("Ⅻ".as_bytes())
+        );
+    }
+
+    #[test]
+    fn try_from_text() {
+        use core::convert::{TryFrom, TryInto};
+
+        let n: Result<Roman, _> = "XVII".try_into();
+        assert_eq!(Ok(17), n.map(Roman::value));
+        assert_eq!(Ok(17), Roman::try_from(&b"xvii"[..]).map(Roman::value));
+        assert_eq!(Err(Error::InvalidDigit(b'Q', 1)), Roman::try_from("XQ"));
+    }
+
+    #[test]
+    fn generic_backends() {
+        use crate::{GenericRoman, Roman32, RomanBackend};
+        use core::mem::size_of;
+
+        fn max<T: RomanBackend>() -> GenericRoman<T> {
+            GenericRoman::from_value(T::MAX).unwrap()
+        }
+
+        assert_eq!("MMMMCMXCIX", max::<u16>().to_string());
+        assert_eq!(Ok(4_999_999), "M\u{304}M\u{304}M\u{304}M\u{304}C\u{304}M\u{304}X\u{304}C\u{304}I\u{304}X\u{304}CMXCIX".parse().map(GenericRoman::<u32>::get));
+        assert_eq!("Roman(17)", format!("{:?}", Roman::new(17).unwrap()));
+        assert_eq!("Roman32(17)", format!("{:?}", Roman32::new(17).unwrap()));
+        assert_eq!(size_of::<u16>(), size_of::<Option<Roman>>());
+    }
 }
@@ -1,73 +1,169 @@
+use super::Style;
+
 pub struct LadderEntry {
     pub upper: &'static str,
     pub lower: &'static str,
+    pub unicode: &'static str,
+    pub braille: &'static str,
     pub value: u16,
 }
 
-pub static VALUES: &[LadderEntry] = &[
+impl LadderEntry {
+    /// Returns the digits of this entry in the given style.
+    pub const fn digits(&self, style: Style) -> &'static str {
+        match style {
+            Style::Lower => self.lower,
+            Style::Upper => self.upper,
+            Style::Unicode => self.unicode,
+            Style::Clock => match self.value {
+                4 => "IIII",
+                _ => self.upper,
+            },
+        }
+    }
+}
+
+pub const VALUES: &[LadderEntry] = &[
     LadderEntry {
         upper: "M",
         lower: "m",
+        unicode: "Ⅿ",
+        braille: "⠍",
         value: 1000,
     },
     LadderEntry {
         upper: "CM",
         lower: "cm",
+        unicode: "ⅭⅯ",
+        braille: "⠉⠍",
         value: 900,
     },
     LadderEntry {
         upper: "D",
         lower: "d",
+        unicode: "Ⅾ",
+        braille: "⠙",
         value: 500,
     },
     LadderEntry {
         upper: "CD",
         lower: "cd",
+        unicode: "ⅭⅮ",
+        braille: "⠉⠙",
         value: 400,
     },
     LadderEntry {
         upper: "C",
         lower: "c",
+        unicode: "Ⅽ",
+        braille: "⠉",
         value: 100,
     },
     LadderEntry {
         upper: "XC",
         lower: "xc",
+        unicode: "ⅩⅭ",
+        braille: "⠭⠉",
         value: 90,
     },
     LadderEntry {
         upper: "L",
         lower: "l",
+        unicode: "Ⅼ",
+        braille: "⠇",
         value: 50,
     },
     LadderEntry {
         upper: "XL",
         lower: "xl",
+        unicode: "ⅩⅬ",
+        braille: "⠭⠇",
         value: 40,
     },
     LadderEntry {
         upper: "X",
         lower: "x",
+        unicode: "Ⅹ",
+        braille: "⠭",
         value: 10,
     },
     LadderEntry {
         upper: "IX",
         lower: "ix",
+        unicode: "Ⅸ",
+        braille: "⠊⠭",
         value: 9,
     },
     LadderEntry {
         upper: "V",
         lower: "v",
+        unicode: "Ⅴ",
+        braille: "⠧",
         value: 5,
     },
     LadderEntry {
         upper: "IV",
         lower: "iv",
+        unicode: "Ⅳ",
+        braille: "⠊⠧",
         value: 4,
     },
     LadderEntry {
         upper: "I",
         lower: "i",
+        unicode: "Ⅰ",
+        braille: "⠊",
         value: 1,
     },
 ];
+
+/// The length in bytes of the longest numeral, `MMMMDCCCLXXXVIII`, which clock style
+/// ties with `MMMMDCCCLXXXIIII`.
+#[cfg_attr(not(feature = "futures"), allow(dead_code))]
+pub const MAX_LEN: usize = 16;
+
+/// The length in bytes of the longest numeral in Unicode style, `ⅯⅯⅯⅯⅮⅭⅭⅭⅬⅩⅩⅩⅧ`, and
+/// so of the longest numeral in any style.
+pub const MAX_UNICODE_LEN: usize = 39;
+
+/// Precomposed Unicode numerals for the values `1..=12`, indexed by value.
+pub const NUMBER_FORMS: [&str; 13] = [
+    "", "Ⅰ", "Ⅱ", "Ⅲ", "Ⅳ", "Ⅴ", "Ⅵ", "Ⅶ", "Ⅷ", "Ⅸ", "Ⅹ", "Ⅺ", "Ⅻ",
+];
+
+/// Returns the part of `value` which Unicode style writes as a single precomposed
+/// numeral: the units, or the last two places where they make eleven or twelve.
+pub const fn unicode_tail(value: u16) -> u16 {
+    match value % 100 {
+        tail @ 11..=12 => tail,
+        _ => value % 10,
+    }
+}
+
+/// Returns the number of bytes `value` occupies when formatted, without formatting it.
+///
+/// Upper- and lowercase forms are always the same length.
+pub fn formatted_len(mut value: u16) -> usize {
+    let mut len = 0;
+
+    for entry in VALUES {
+        let count = value / entry.value;
+        len += usize::from(count) * entry.upper.len();
+        value -= count * entry.value;
+    }
+
+    len
+}
+
+/// Returns the number of characters `value` occupies when formatted in `style`.
+pub fn formatted_chars(value: u16, style: Style) -> usize {
+    match style {
+        Style::Lower | Style::Upper => formatted_len(value),
+        Style::Unicode => {
+            let tail = unicode_tail(value);
+            formatted_len(value - tail) + usize::from(tail > 0)
+        }
+        // Clock style spells out a final `IV` as `IIII`.
+        Style::Clock => formatted_len(value) + if value % 10 == 4 { 2 } else { 0 },
+    }
+}
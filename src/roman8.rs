@@ -0,0 +1,164 @@
+use crate::{
+    backend::{sealed::Sealed, RomanBackend},
+    unit, Error, GenericRoman, Result, Roman, RomanFormatter, Style,
+};
+use core::{
+    convert::TryFrom,
+    fmt::{self, Display},
+    num::{NonZeroU16, NonZeroU8},
+};
+
+/// A Roman numeral stored in a single byte.
+///
+/// `Roman8` covers the range `1..=255`, enough for most chapter, page and volume
+/// numbers, and takes up a single byte even inside an [`Option`]. It is written and
+/// parsed exactly as a [`Roman`], and converts losslessly to one.
+///
+/// ## Examples
+///
+/// ```
+/// use xvii::{Roman, Roman8};
+/// use std::convert::TryFrom;
+///
+/// let n: Roman8 = "CCLV".parse().unwrap();
+/// assert_eq!(n.value(), 255);
+/// assert_eq!(Roman::from(n).value(), 255);
+/// assert!(Roman8::try_from(Roman::new(256).unwrap()).is_err());
+/// assert_eq!(std::mem::size_of::<Option<Roman8>>(), 1);
+/// ```
+pub type Roman8 = GenericRoman<u8>;
+
+impl Roman8 {
+    /// The length in bytes of the longest `Roman8` in any [`Style`], `ⅭⅭⅩⅩⅩⅧ`.
+    pub const MAX_FORMATTED_LEN: usize = 18;
+
+    /// Creates a `Roman8` value based on a [`u8`].
+    ///
+    /// Returns [`Error::OutOfRange`] for zero, as [`Roman::new`] does; every other
+    /// value is in range.
+    pub const fn new(n: u8) -> Result<Roman8> {
+        match NonZeroU8::new(n) {
+            Some(n) => Ok(GenericRoman(n)),
+            None => Err(Error::OutOfRange(0)),
+        }
+    }
+
+    /// Returns a [`RomanFormatter`] which lazily formats `self` as a lowercase or
+    /// uppercase numeral depending on `style`.
+    pub const fn format(self, style: Style) -> RomanFormatter {
+        self.widen().format(style)
+    }
+
+    /// Returns the value of this numeral.
+    pub const fn value(self) -> u8 {
+        self.0.get()
+    }
+
+    /// Returns the inner value.
+    pub const fn into_inner(self) -> NonZeroU8 {
+        self.0
+    }
+
+    const fn widen(self) -> Roman {
+        // Every value up to 255 is a valid `Roman`.
+        match NonZeroU16::new(self.0.get() as u16) {
+            Some(n) => GenericRoman(n),
+            None => unreachable!(),
+        }
+    }
+}
+
+impl From<Roman8> for Roman {
+    fn from(n: Roman8) -> Self {
+        n.widen()
+    }
+}
+
+impl TryFrom<Roman> for Roman8 {
+    type Error = Error;
+
+    /// Fails with [`Error::OutOfRange`] for values above 255.
+    fn try_from(n: Roman) -> Result<Self> {
+        let n = n.value();
+        u8::try_from(n)
+            .map_err(|_| Error::OutOfRange(n))
+            .and_then(Roman8::new)
+    }
+}
+
+impl Sealed for u8 {}
+
+impl RomanBackend for u8 {
+    type NonZero = NonZeroU8;
+
+    const MAX: u8 = u8::MAX;
+    const NAME: &'static str = "Roman8";
+
+    fn new(n: u8) -> Result<Roman8> {
+        Roman8::new(n)
+    }
+
+    fn get(n: Roman8) -> u8 {
+        n.value()
+    }
+
+    fn fmt(n: Roman8, style: Style, f: &mut fmt::Formatter) -> fmt::Result {
+        Display::fmt(&n.format(style), f)
+    }
+
+    fn parse(s: &str) -> Result<Roman8> {
+        let n = unit::sum(s)?;
+        u8::try_from(n)
+            .map_err(|_| Error::OutOfRange(n))
+            .and_then(Roman8::new)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Roman8;
+    use crate::{Error, Roman, Style};
+    use core::convert::TryFrom;
+
+    #[test]
+    fn matches_roman() {
+        for n in 1..=u8::MAX {
+            let roman = Roman::new(n.into()).unwrap();
+            let compact = Roman8::new(n).unwrap();
+            assert_eq!(roman.to_string(), compact.to_string());
+            assert_eq!(Ok(compact), roman.to_string().parse());
+            assert_eq!(roman, Roman::from(compact));
+            assert_eq!(Ok(compact), Roman8::try_from(roman));
+        }
+        assert_eq!(
+            "ccl",
+            Roman8::new(250).unwrap().format(Style::Lower).to_string()
+        );
+    }
+
+    #[test]
+    fn max_formatted_len() {
+        let longest = (1..=u8::MAX)
+            .map(|n| {
+                Roman8::new(n)
+                    .unwrap()
+                    .format(Style::Unicode)
+                    .to_string()
+                    .len()
+            })
+            .max();
+        assert_eq!(Some(Roman8::MAX_FORMATTED_LEN), longest);
+    }
+
+    #[test]
+    fn rejects_out_of_range() {
+        assert_eq!(Err(Error::OutOfRange(0)), Roman8::new(0));
+        assert_eq!(Err(Error::OutOfRange(256)), "CCLVI".parse::<Roman8>());
+        assert_eq!(Err(Error::OutOfRange(5000)), "MMMMM".parse::<Roman8>());
+        assert_eq!(Err(Error::OutOfRange(0)), "".parse::<Roman8>());
+        assert_eq!(
+            Err(Error::OutOfRange(4999)),
+            Roman8::try_from(Roman::new(4999).unwrap())
+        );
+    }
+}
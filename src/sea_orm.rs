@@ -0,0 +1,113 @@
+//! [SeaORM](https://www.sea-ql.org/SeaORM) support.
+//!
+//! With this feature enabled, `Roman` can be used directly as a field in an entity
+//! model. It is written to the database as a `SMALLINT`, but may be read from
+//! either an integer column or a `TEXT` column holding numerals such as `"XIV"`.
+//!
+//! ```rust
+//! # use std::convert::TryInto;
+//! use sea_orm::entity::prelude::*;
+//!
+//! #[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+//! #[sea_orm(table_name = "chapter")]
+//! pub struct Model {
+//!     #[sea_orm(primary_key)]
+//!     pub id: i32,
+//!     pub number: xvii::Roman,
+//!     pub appendix: Option<xvii::Roman>,
+//! }
+//! # #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+//! # pub enum Relation {}
+//! # impl ActiveModelBehavior for ActiveModel {}
+//! ```
+
+use crate::{Error, Roman};
+use sea_orm::{
+    sea_query::{ArrayType, ColumnType, Nullable, ValueType, ValueTypeErr},
+    ColIdx, DbErr, QueryResult, TryGetError, TryGetable, Value,
+};
+use std::convert::TryFrom;
+
+impl From<Roman> for Value {
+    fn from(roman: Roman) -> Value {
+        // Lossless: no numeral exceeds 4999.
+        Value::SmallInt(Some(roman.value() as i16))
+    }
+}
+
+impl Nullable for Roman {
+    fn null() -> Value {
+        Value::SmallInt(None)
+    }
+}
+
+impl ValueType for Roman {
+    fn try_from(v: Value) -> Result<Self, ValueTypeErr> {
+        let roman = match v {
+            Value::SmallInt(Some(n)) => from_int(n.into()),
+            Value::Int(Some(n)) => from_int(n.into()),
+            Value::BigInt(Some(n)) => from_int(n),
+            Value::SmallUnsigned(Some(n)) => Roman::new(n),
+            Value::String(Some(s)) => s.parse(),
+            _ => return Err(ValueTypeErr),
+        };
+        roman.map_err(|_| ValueTypeErr)
+    }
+
+    fn type_name() -> String {
+        "Roman".to_owned()
+    }
+
+    fn array_type() -> ArrayType {
+        ArrayType::SmallInt
+    }
+
+    fn column_type() -> ColumnType {
+        ColumnType::SmallInteger
+    }
+}
+
+impl TryGetable for Roman {
+    fn try_get_by<I: ColIdx>(res: &QueryResult, index: I) -> Result<Self, TryGetError> {
+        let roman = match i16::try_get_by(res, index) {
+            Ok(n) => from_int(n.into()),
+            // Not an integer column, so try it as text.
+            Err(TryGetError::DbErr(_)) => String::try_get_by(res, index)?.parse(),
+            Err(e) => return Err(e),
+        };
+        roman.map_err(|e| TryGetError::DbErr(DbErr::Type(format!("{:?}: {}", index, e))))
+    }
+}
+
+fn from_int(n: i64) -> Result<Roman, Error> {
+    <u16 as TryFrom<i64>>::try_from(n)
+        .map_err(|_| Error::Overflow)
+        .and_then(Roman::new)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Roman;
+    use sea_orm::{sea_query::ValueType, Value};
+
+    #[test]
+    fn round_trips_through_smallint() {
+        let roman = Roman::new(1984).unwrap();
+        let value = Value::from(roman);
+        assert_eq!(Value::SmallInt(Some(1984)), value);
+        assert_eq!(roman, <Roman as ValueType>::try_from(value).unwrap());
+    }
+
+    #[test]
+    fn accepts_text() {
+        let value = Value::String(Some("xiv".to_owned()));
+        assert_eq!(14, <Roman as ValueType>::try_from(value).unwrap().value());
+    }
+
+    #[test]
+    fn rejects_out_of_range() {
+        assert!(<Roman as ValueType>::try_from(Value::Int(Some(0))).is_err());
+        assert!(<Roman as ValueType>::try_from(Value::BigInt(Some(-7))).is_err());
+        assert!(<Roman as ValueType>::try_from(Value::SmallInt(None)).is_err());
+    }
+}
@@ -0,0 +1,111 @@
+use crate::{Error, Roman};
+use core::{
+    convert::TryFrom,
+    fmt::{self, Display},
+};
+
+/// A break in a sequence of numerals.
+///
+/// Returned by [`validate_sequence`]. Indices are zero-based positions in the sequence.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum SequenceError {
+    /// The item at the given index is not a valid numeral.
+    Invalid(usize, Error),
+    /// The numeral given is missing at the given index.
+    Gap(usize, Roman),
+    /// The item at the first index repeats the value of the item at the second.
+    Duplicate(usize, usize),
+}
+
+impl Display for SequenceError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SequenceError::Invalid(index, e) => write!(f, "Item {}: {}", index, e),
+            SequenceError::Gap(index, missing) => {
+                write!(f, "Item {}: expected {}", index, missing)
+            }
+            SequenceError::Duplicate(index, first) => {
+                write!(f, "Item {}: duplicates item {}", index, first)
+            }
+        }
+    }
+}
+
+impl core::error::Error for SequenceError {}
+
+/// Checks that a list of numerals counts up from `I` without gaps or repeats.
+///
+/// Stops at the first problem found. Because the sequence must be contiguous from `I`,
+/// any value lower than expected has already appeared, and is reported as a duplicate
+/// of that earlier item.
+///
+/// ## Examples
+///
+/// ```
+/// use xvii::{validate_sequence, Roman, SequenceError};
+///
+/// assert_eq!(validate_sequence(["I", "II", "III"]), Ok(()));
+/// assert_eq!(
+///     validate_sequence(["I", "II", "IV"]),
+///     Err(SequenceError::Gap(2, Roman::new(3).unwrap()))
+/// );
+/// assert_eq!(
+///     validate_sequence(["I", "II", "III", "II"]),
+///     Err(SequenceError::Duplicate(3, 1))
+/// );
+/// ```
+pub fn validate_sequence<I>(items: I) -> Result<(), SequenceError>
+where
+    I: IntoIterator,
+    I::Item: AsRef<str>,
+{
+    for (index, item) in items.into_iter().enumerate() {
+        let value = item
+            .as_ref()
+            .parse::<Roman>()
+            .map_err(|e| SequenceError::Invalid(index, e))?
+            .value();
+        let expected = index + 1;
+
+        match usize::from(value) {
+            n if n == expected => {}
+            n if n < expected => return Err(SequenceError::Duplicate(index, n - 1)),
+            _ => {
+                let missing = u16::try_from(expected)
+                    .ok()
+                    .and_then(|n| Roman::new(n).ok())
+                    .ok_or(SequenceError::Invalid(index, Error::OutOfRange(value)))?;
+                return Err(SequenceError::Gap(index, missing));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{validate_sequence, SequenceError};
+    use crate::{Error, Roman};
+
+    #[test]
+    fn empty_sequence_is_valid() {
+        assert_eq!(Ok(()), validate_sequence(core::iter::empty::<&str>()));
+    }
+
+    #[test]
+    fn must_start_at_i() {
+        assert_eq!(
+            Err(SequenceError::Gap(0, Roman::new(1).unwrap())),
+            validate_sequence(["II", "III"])
+        );
+    }
+
+    #[test]
+    fn reports_invalid_items() {
+        assert_eq!(
+            Err(SequenceError::Invalid(1, Error::InvalidDigit(b'Q', 0))),
+            validate_sequence(["i", "Q"])
+        );
+    }
+}
@@ -0,0 +1,128 @@
+use crate::{Error, Roman};
+use core::{convert::TryFrom, fmt};
+use serde::{
+    de::{self, Unexpected, Visitor},
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+
+/// Serializes as the uppercase numeral, e.g. `"XVII"`, or as a `u16` in formats which
+/// are not human-readable.
+impl Serialize for Roman {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match serializer.is_human_readable() {
+            true => serializer.collect_str(self),
+            false => serializer.serialize_u16(self.value()),
+        }
+    }
+}
+
+/// Deserializes from a numeral in either case, or from a `u16` in the range `1..=4999`
+/// in formats which are not human-readable.
+///
+/// Neither asks the format to describe its own input, so that numerals can be read
+/// back from formats such as bincode and postcard.
+impl<'de> Deserialize<'de> for Roman {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match deserializer.is_human_readable() {
+            true => deserializer.deserialize_str(RomanVisitor),
+            false => deserializer.deserialize_u16(RomanVisitor),
+        }
+    }
+}
+
+/// Accepts either a numeral string or an integer.
+pub(crate) struct RomanVisitor;
+
+impl<'de> Visitor<'de> for RomanVisitor {
+    type Value = Roman;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a Roman numeral or an integer in the range 1..=4999")
+    }
+
+    fn visit_i64<E: de::Error>(self, v: i64) -> Result<Roman, E> {
+        u16::try_from(v)
+            .map_err(|_| Error::Overflow)
+            .and_then(Roman::new)
+            .map_err(|_| E::invalid_value(Unexpected::Signed(v), &self))
+    }
+
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<Roman, E> {
+        u16::try_from(v)
+            .map_err(|_| Error::Overflow)
+            .and_then(Roman::new)
+            .map_err(|_| E::invalid_value(Unexpected::Unsigned(v), &self))
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Roman, E> {
+        v.parse().map_err(E::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Error, Roman};
+    use serde::{
+        de::{
+            self,
+            value::{Error as ValueError, I64Deserializer, StrDeserializer},
+            Deserialize, Deserializer, IntoDeserializer, Visitor,
+        },
+        forward_to_deserialize_any,
+    };
+
+    /// A binary format which cannot describe its own input.
+    struct Compact(u16);
+
+    impl<'de> Deserializer<'de> for Compact {
+        type Error = ValueError;
+
+        fn deserialize_any<V: Visitor<'de>>(self, _: V) -> Result<V::Value, ValueError> {
+            Err(de::Error::custom("deserialize_any is not supported"))
+        }
+
+        fn deserialize_u16<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, ValueError> {
+            visitor.visit_u16(self.0)
+        }
+
+        fn is_human_readable(&self) -> bool {
+            false
+        }
+
+        forward_to_deserialize_any! {
+            bool i8 i16 i32 i64 i128 u8 u32 u64 u128 f32 f64 char str string bytes
+            byte_buf option unit unit_struct newtype_struct seq tuple tuple_struct map
+            struct enum identifier ignored_any
+        }
+    }
+
+    #[test]
+    fn deserialize_str() {
+        let de: StrDeserializer<ValueError> = "xiv".into_deserializer();
+        assert_eq!(14, Roman::deserialize(de).unwrap().value());
+
+        let de: StrDeserializer<ValueError> = "XQI".into_deserializer();
+        assert!(Roman::deserialize(de).is_err());
+    }
+
+    #[test]
+    fn deserialize_int() {
+        let de: I64Deserializer<ValueError> = 1984_i64.into_deserializer();
+        assert_eq!(1984, Roman::deserialize(de).unwrap().value());
+
+        let de: I64Deserializer<ValueError> = 5000_i64.into_deserializer();
+        assert!(Roman::deserialize(de).is_err());
+    }
+
+    #[test]
+    fn deserialize_compact() {
+        assert_eq!(1984, Roman::deserialize(Compact(1984)).unwrap().value());
+        assert!(Roman::deserialize(Compact(0)).is_err());
+    }
+
+    #[test]
+    fn deserialize_error() {
+        let de: StrDeserializer<ValueError> = "Overflow".into_deserializer();
+        assert_eq!(Error::Overflow, Error::deserialize(de).unwrap());
+    }
+}
@@ -0,0 +1,230 @@
+use crate::{GenericRoman, Roman};
+use core::{
+    iter::{FromIterator, FusedIterator},
+    num::NonZeroU16,
+};
+
+const WORDS: usize = 4999_usize.div_ceil(64);
+
+/// A set of numerals, stored as one bit per value.
+///
+/// The set covers the whole range `1..=4999` in a fixed 632 bytes, with no allocation,
+/// and iterates in numeral order.
+///
+/// ## Examples
+///
+/// ```
+/// use xvii::{Roman, RomanSet};
+///
+/// let evens: RomanSet = (2..=20).step_by(2).map(|n| Roman::new(n).unwrap()).collect();
+/// let threes: RomanSet = (3..=20).step_by(3).map(|n| Roman::new(n).unwrap()).collect();
+///
+/// let sixes: Vec<_> = evens.intersection(&threes).iter().map(|n| n.to_string()).collect();
+/// assert_eq!(sixes, ["VI", "XII", "XVIII"]);
+/// assert_eq!(evens.union(&threes).len(), 13);
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct RomanSet {
+    words: [u64; WORDS],
+}
+
+impl RomanSet {
+    /// Creates an empty set.
+    pub const fn new() -> Self {
+        RomanSet { words: [0; WORDS] }
+    }
+
+    /// Returns the number of numerals in the set.
+    pub fn len(&self) -> usize {
+        self.words.iter().map(|w| w.count_ones() as usize).sum()
+    }
+
+    /// Returns `true` if the set is empty.
+    pub fn is_empty(&self) -> bool {
+        self.words.iter().all(|&w| w == 0)
+    }
+
+    /// Returns `true` if the set contains `n`.
+    pub const fn contains(&self, n: Roman) -> bool {
+        let (word, bit) = position(n);
+        self.words[word] & bit != 0
+    }
+
+    /// Adds `n` to the set, returning `true` if it was not already present.
+    pub fn insert(&mut self, n: Roman) -> bool {
+        let (word, bit) = position(n);
+        let absent = self.words[word] & bit == 0;
+        self.words[word] |= bit;
+        absent
+    }
+
+    /// Removes `n` from the set, returning `true` if it was present.
+    pub fn remove(&mut self, n: Roman) -> bool {
+        let (word, bit) = position(n);
+        let present = self.words[word] & bit != 0;
+        self.words[word] &= !bit;
+        present
+    }
+
+    /// Removes every numeral from the set.
+    pub fn clear(&mut self) {
+        self.words = [0; WORDS];
+    }
+
+    /// Returns the set of numerals in either `self` or `other`.
+    pub fn union(&self, other: &RomanSet) -> RomanSet {
+        self.zip_with(other, |a, b| a | b)
+    }
+
+    /// Returns the set of numerals in both `self` and `other`.
+    pub fn intersection(&self, other: &RomanSet) -> RomanSet {
+        self.zip_with(other, |a, b| a & b)
+    }
+
+    /// Returns the set of numerals in `self` but not in `other`.
+    pub fn difference(&self, other: &RomanSet) -> RomanSet {
+        self.zip_with(other, |a, b| a & !b)
+    }
+
+    /// Returns an iterator over the numerals in the set, in ascending order.
+    pub fn iter(&self) -> SetIter<'_> {
+        SetIter {
+            words: &self.words,
+            index: 0,
+            current: self.words[0],
+            remaining: self.len(),
+        }
+    }
+
+    fn zip_with(&self, other: &RomanSet, f: impl Fn(u64, u64) -> u64) -> RomanSet {
+        let mut result = RomanSet::new();
+        for (word, (&a, &b)) in result
+            .words
+            .iter_mut()
+            .zip(self.words.iter().zip(&other.words))
+        {
+            *word = f(a, b);
+        }
+        result
+    }
+}
+
+impl Default for RomanSet {
+    fn default() -> Self {
+        RomanSet::new()
+    }
+}
+
+impl FromIterator<Roman> for RomanSet {
+    fn from_iter<I: IntoIterator<Item = Roman>>(iter: I) -> Self {
+        let mut set = RomanSet::new();
+        set.extend(iter);
+        set
+    }
+}
+
+impl Extend<Roman> for RomanSet {
+    fn extend<I: IntoIterator<Item = Roman>>(&mut self, iter: I) {
+        for n in iter {
+            self.insert(n);
+        }
+    }
+}
+
+impl<'a> IntoIterator for &'a RomanSet {
+    type Item = Roman;
+    type IntoIter = SetIter<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Iterator over the numerals in a [`RomanSet`], in ascending order.
+///
+/// This struct is created by the [`iter`](RomanSet::iter) method.
+#[derive(Clone, Debug)]
+pub struct SetIter<'a> {
+    words: &'a [u64; WORDS],
+    index: usize,
+    current: u64,
+    remaining: usize,
+}
+
+impl Iterator for SetIter<'_> {
+    type Item = Roman;
+
+    fn next(&mut self) -> Option<Roman> {
+        while self.current == 0 {
+            self.index += 1;
+            self.current = *self.words.get(self.index)?;
+        }
+
+        let bit = self.current.trailing_zeros() as usize;
+        self.current &= self.current - 1;
+        self.remaining -= 1;
+
+        // Only bits for valid numerals are ever set.
+        let value = (self.index * 64 + bit) as u16;
+        Some(GenericRoman(NonZeroU16::MIN.saturating_add(value)))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl ExactSizeIterator for SetIter<'_> {}
+
+impl FusedIterator for SetIter<'_> {}
+
+/// Returns the word index and bit mask for `n`.
+const fn position(n: Roman) -> (usize, u64) {
+    let index = (n.value() - 1) as usize;
+    (index / 64, 1 << (index % 64))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RomanSet;
+    use crate::Roman;
+
+    fn roman(n: u16) -> Roman {
+        Roman::new(n).unwrap()
+    }
+
+    #[test]
+    fn insert_contains_remove() {
+        let mut set = RomanSet::new();
+        assert!(set.insert(roman(1)));
+        assert!(set.insert(roman(4999)));
+        assert!(!set.insert(roman(4999)));
+        assert!(set.contains(roman(4999)));
+        assert!(!set.contains(roman(64)));
+        assert_eq!(2, set.len());
+
+        assert!(set.remove(roman(1)));
+        assert!(!set.remove(roman(1)));
+        assert_eq!(vec![roman(4999)], set.iter().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn iterates_across_words() {
+        let values = [1, 63, 64, 65, 128, 129, 4999];
+        let set: RomanSet = values.iter().map(|&n| roman(n)).collect();
+
+        let iter = set.iter();
+        assert_eq!(values.len(), iter.len());
+        let found: Vec<_> = iter.map(Roman::value).collect();
+        assert_eq!(&values[..], &found[..]);
+    }
+
+    #[test]
+    fn difference() {
+        let a: RomanSet = (1..=5).map(roman).collect();
+        let b: RomanSet = (3..=9).map(roman).collect();
+        let values: Vec<_> = a.difference(&b).iter().map(Roman::value).collect();
+        assert_eq!(vec![1, 2], values);
+        assert!(RomanSet::new().is_empty());
+    }
+}
@@ -0,0 +1,154 @@
+use crate::{Error, GenericRoman, Result, Roman, Style};
+use core::{
+    fmt::{self, Display},
+    num::{NonZeroI16, NonZeroU16},
+    ops::Neg,
+    str::FromStr,
+};
+
+/// A numeral which may be negative.
+///
+/// `RomanSigned` covers `-4999..=-1` and `1..=4999`. Negative values are written
+/// with a leading `-`; when parsing, a leading `-`, `−` (U+2212) or `+` is accepted.
+///
+/// ## Examples
+///
+/// ```
+/// use xvii::RomanSigned;
+///
+/// let n: RomanSigned = "-XVII".parse().unwrap();
+/// assert_eq!(n.value(), -17);
+/// assert_eq!(n.magnitude().value(), 17);
+/// assert_eq!((-n).to_string(), "XVII");
+/// ```
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct RomanSigned(NonZeroI16);
+
+impl RomanSigned {
+    /// Creates a `RomanSigned` value based on an [`i16`].
+    ///
+    /// Fails with [`Error::Zero`] for zero, and with [`Error::OutOfRange`] holding the
+    /// magnitude of values beyond 4999 either side of it.
+    pub const fn new(n: i16) -> Result<RomanSigned> {
+        match NonZeroI16::new(n) {
+            None => Err(Error::Zero),
+            Some(n) if n.get().unsigned_abs() <= 4999 => Ok(RomanSigned(n)),
+            Some(n) => Err(Error::OutOfRange(n.get().unsigned_abs())),
+        }
+    }
+
+    /// Returns the value of this numeral.
+    pub const fn value(self) -> i16 {
+        self.0.get()
+    }
+
+    /// Returns the value without its sign.
+    pub const fn magnitude(self) -> Roman {
+        // The magnitude is always within 1..=4999.
+        match NonZeroU16::new(self.0.get().unsigned_abs()) {
+            Some(n) => GenericRoman(n),
+            None => unreachable!(),
+        }
+    }
+
+    /// Returns `true` if the value is negative.
+    pub const fn is_negative(self) -> bool {
+        self.0.get() < 0
+    }
+
+    /// Returns a [`SignedFormatter`] which lazily formats `self` in the given style.
+    pub const fn format(self, style: Style) -> SignedFormatter {
+        SignedFormatter { value: self, style }
+    }
+}
+
+impl From<Roman> for RomanSigned {
+    fn from(n: Roman) -> Self {
+        // At most 4999, so always fits.
+        RomanSigned(NonZeroI16::new(n.value() as i16).unwrap())
+    }
+}
+
+impl Neg for RomanSigned {
+    type Output = RomanSigned;
+
+    fn neg(self) -> RomanSigned {
+        RomanSigned(-self.0)
+    }
+}
+
+/// Lazy formatter for [`RomanSigned`] values.
+///
+/// This struct is created by the [`format`](RomanSigned::format) method.
+#[derive(Debug, Copy, Clone)]
+pub struct SignedFormatter {
+    value: RomanSigned,
+    style: Style,
+}
+
+impl Display for SignedFormatter {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.value.is_negative() {
+            f.write_str("-")?;
+        }
+        self.value.magnitude().format(self.style).fmt(f)
+    }
+}
+
+impl Display for RomanSigned {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let style = crate::global_style::display_style();
+
+        self.format(style).fmt(f)
+    }
+}
+
+impl FromStr for RomanSigned {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let (negative, digits) = match s.chars().next() {
+            Some(c @ '-') | Some(c @ '−') => (true, &s[c.len_utf8()..]),
+            Some('+') => (false, &s[1..]),
+            _ => (false, s),
+        };
+
+        let magnitude = digits
+            .parse::<Roman>()
+            .map_err(|e| e.shifted(s.len() - digits.len()))?;
+        let magnitude = RomanSigned::from(magnitude);
+        Ok(if negative { -magnitude } else { magnitude })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RomanSigned;
+    use crate::{Error, Style};
+
+    #[test]
+    fn parses_signs() {
+        assert_eq!(Ok(-4999), "-MMMMCMXCIX".parse().map(RomanSigned::value));
+        assert_eq!(Ok(-4), "−iv".parse().map(RomanSigned::value));
+        assert_eq!(Ok(4), "+IV".parse().map(RomanSigned::value));
+        assert_eq!(
+            Err(Error::InvalidDigit(b'-', 1)),
+            "--IV".parse::<RomanSigned>()
+        );
+        assert_eq!(Err(Error::OutOfRange(0)), "-".parse::<RomanSigned>());
+    }
+
+    #[test]
+    fn formats_signs() {
+        let n = RomanSigned::new(-12).unwrap();
+        assert_eq!("-xii", n.format(Style::Lower).to_string());
+        assert_eq!("XII", (-n).to_string());
+    }
+
+    #[test]
+    fn range() {
+        assert_eq!(Err(Error::Zero), RomanSigned::new(0));
+        assert_eq!(Err(Error::OutOfRange(5000)), RomanSigned::new(-5000));
+        assert_eq!(Err(Error::OutOfRange(32768)), RomanSigned::new(i16::MIN));
+    }
+}
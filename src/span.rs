@@ -0,0 +1,157 @@
+use crate::{Error, Roman};
+use core::{
+    fmt::{self, Display},
+    str::FromStr,
+};
+
+/// An error in parsing or constructing a [`RomanSpan`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum SpanError {
+    /// The numeral before the dash is not valid.
+    Start(Error),
+    /// The numeral after the dash is not valid.
+    End(Error),
+    /// The end of the range comes before its start.
+    Reversed(Roman, Roman),
+}
+
+impl Display for SpanError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SpanError::Start(e) => write!(f, "Invalid start of range: {}", e),
+            SpanError::End(e) => write!(f, "Invalid end of range: {}", e),
+            SpanError::Reversed(start, end) => {
+                write!(f, "End of range comes before start: {}–{}", start, end)
+            }
+        }
+    }
+}
+
+impl core::error::Error for SpanError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            SpanError::Start(e) | SpanError::End(e) => Some(e),
+            SpanError::Reversed(..) => None,
+        }
+    }
+}
+
+/// An inclusive range of numerals, such as the front-matter pages `xii–xiv`.
+///
+/// Parses from two numerals separated by a hyphen, an en dash, or the double hyphen
+/// used by BibTeX, with optional whitespace around the separator. A lone numeral
+/// parses as a span of one. Formats with an en dash.
+///
+/// ## Examples
+///
+/// ```
+/// use xvii::{RomanSpan, SpanError};
+///
+/// let pages: RomanSpan = "xii–xiv".parse().unwrap();
+/// assert_eq!(pages.start().value(), 12);
+/// assert_eq!(pages.end().value(), 14);
+/// assert_eq!(pages.len(), 3);
+/// assert_eq!(pages.to_string(), "XII–XIV");
+///
+/// assert!(matches!("XIV-XII".parse::<RomanSpan>(), Err(SpanError::Reversed(..))));
+/// ```
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct RomanSpan {
+    start: Roman,
+    end: Roman,
+}
+
+impl RomanSpan {
+    /// Creates a span from its first and last numerals.
+    pub fn new(start: Roman, end: Roman) -> Result<Self, SpanError> {
+        if end < start {
+            Err(SpanError::Reversed(start, end))
+        } else {
+            Ok(RomanSpan { start, end })
+        }
+    }
+
+    /// Returns the first numeral of the span.
+    pub const fn start(&self) -> Roman {
+        self.start
+    }
+
+    /// Returns the last numeral of the span.
+    pub const fn end(&self) -> Roman {
+        self.end
+    }
+
+    /// Returns the number of values in the span, which is never zero.
+    #[allow(clippy::len_without_is_empty)]
+    pub const fn len(&self) -> u16 {
+        self.end.value() - self.start.value() + 1
+    }
+
+    /// Tests whether `n` lies within the span.
+    pub fn contains(&self, n: Roman) -> bool {
+        (self.start..=self.end).contains(&n)
+    }
+}
+
+impl Display for RomanSpan {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}–{}", self.start, self.end)
+    }
+}
+
+impl FromStr for RomanSpan {
+    type Err = SpanError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (start, end) = match s.split_once(['-', '–']) {
+            Some((start, end)) => (start, end.strip_prefix('-').unwrap_or(end)),
+            None => (s, s),
+        };
+
+        let start = start.trim().parse().map_err(SpanError::Start)?;
+        let end = end.trim().parse().map_err(SpanError::End)?;
+        RomanSpan::new(start, end)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{RomanSpan, SpanError};
+    use crate::{Error, Roman};
+
+    fn span(s: &str) -> Result<(u16, u16), SpanError> {
+        s.parse::<RomanSpan>()
+            .map(|span| (span.start().value(), span.end().value()))
+    }
+
+    #[test]
+    fn separators() {
+        assert_eq!(Ok((12, 14)), span("XII-XIV"));
+        assert_eq!(Ok((12, 14)), span("xii – xiv"));
+        assert_eq!(Ok((12, 14)), span("xii--xiv"));
+        assert_eq!(Ok((7, 7)), span(" vii "));
+    }
+
+    #[test]
+    fn errors_name_the_side() {
+        assert_eq!(Err(SpanError::Start(Error::OutOfRange(0))), span("-xiv"));
+        assert_eq!(
+            Err(SpanError::End(Error::InvalidDigit(b'q', 0))),
+            span("xii-q")
+        );
+        assert_eq!(
+            Err(SpanError::Reversed(
+                Roman::new(14).unwrap(),
+                Roman::new(12).unwrap()
+            )),
+            span("xiv–xii")
+        );
+    }
+
+    #[test]
+    fn contains() {
+        let span: RomanSpan = "v-x".parse().unwrap();
+        assert!(span.contains(Roman::new(5).unwrap()));
+        assert!(!span.contains(Roman::new(11).unwrap()));
+    }
+}
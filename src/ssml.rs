@@ -0,0 +1,73 @@
+use crate::Roman;
+use core::fmt::{self, Display};
+
+impl Roman {
+    /// Returns an [`Ssml`] which lazily formats a numeral as SSML markup for
+    /// text-to-speech engines.
+    ///
+    /// Speech engines tend to read Roman numerals as letters ("ex vee eye eye") or
+    /// guess wrongly at their meaning. The markup makes the engine read the value as a
+    /// cardinal number instead. Use [`words`](Roman::words) where a plain spelled-out
+    /// form is wanted.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use xvii::Roman;
+    ///
+    /// let chapter = Roman::new(17).unwrap();
+    /// assert_eq!(
+    ///     format!("<speak>Chapter {}</speak>", chapter.ssml()),
+    ///     r#"<speak>Chapter <say-as interpret-as="cardinal">17</say-as></speak>"#
+    /// );
+    /// assert_eq!(
+    ///     chapter.ssml().with_alias().to_string(),
+    ///     r#"<sub alias="seventeen">XVII</sub>"#
+    /// );
+    /// ```
+    pub const fn ssml(self) -> Ssml {
+        Ssml {
+            roman: self,
+            alias: false,
+        }
+    }
+}
+
+/// Lazy SSML formatter.
+///
+/// This struct is created by the [`ssml`](Roman::ssml) method.
+#[derive(Debug, Copy, Clone)]
+pub struct Ssml {
+    roman: Roman,
+    alias: bool,
+}
+
+impl Ssml {
+    /// Formats the numeral itself inside a `<sub>` element whose alias is the spelled
+    /// form, so that the text keeps the numeral while the engine speaks the words.
+    pub const fn with_alias(self) -> Ssml {
+        Ssml {
+            alias: true,
+            ..self
+        }
+    }
+}
+
+impl Display for Ssml {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.alias {
+            write!(
+                f,
+                r#"<sub alias="{}">{}</sub>"#,
+                self.roman.words(),
+                self.roman
+            )
+        } else {
+            write!(
+                f,
+                r#"<say-as interpret-as="cardinal">{}</say-as>"#,
+                self.roman.value()
+            )
+        }
+    }
+}
@@ -0,0 +1,108 @@
+use crate::Style;
+use core::{
+    fmt::{self, Display},
+    str::FromStr,
+};
+
+impl Style {
+    /// Every style, in the order they were added, for presenting a choice between
+    /// them.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use xvii::{Roman, Style};
+    ///
+    /// let n = Roman::new(14).unwrap();
+    /// let all: Vec<_> = Style::ALL
+    ///     .iter()
+    ///     .map(|&style| format!("{}: {}", style, n.format(style)))
+    ///     .collect();
+    /// assert_eq!(all, ["lower: xiv", "upper: XIV", "unicode: ⅩⅣ", "clock: XIIII"]);
+    /// ```
+    pub const ALL: [Style; 4] = [Style::Lower, Style::Upper, Style::Unicode, Style::Clock];
+
+    /// Returns the name of the style, as `Display` writes it and `FromStr` reads it.
+    pub const fn name(self) -> &'static str {
+        match self {
+            Style::Lower => "lower",
+            Style::Upper => "upper",
+            Style::Unicode => "unicode",
+            Style::Clock => "clock",
+        }
+    }
+}
+
+/// The default style is [`Style::Upper`]. This is fixed, and does not follow the
+/// style `Display` uses when it is changed with the `global-style` feature.
+impl Default for Style {
+    fn default() -> Self {
+        Style::Upper
+    }
+}
+
+impl Display for Style {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.pad(self.name())
+    }
+}
+
+/// The error returned when parsing a [`Style`] from a string fails.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct ParseStyleError(());
+
+impl Display for ParseStyleError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("Unknown style: expected upper, lower, unicode or clock")
+    }
+}
+
+impl core::error::Error for ParseStyleError {}
+
+/// Reads a style from its name, `upper`, `lower`, `unicode` or `clock`, in any case,
+/// so that it can be taken from a command-line flag or a configuration file.
+///
+/// ## Examples
+///
+/// ```
+/// use xvii::Style;
+///
+/// assert_eq!("lower".parse(), Ok(Style::Lower));
+/// assert_eq!("Upper".parse(), Ok(Style::Upper));
+/// assert!("title".parse::<Style>().is_err());
+/// ```
+impl FromStr for Style {
+    type Err = ParseStyleError;
+
+    fn from_str(s: &str) -> Result<Self, ParseStyleError> {
+        Style::ALL
+            .iter()
+            .copied()
+            .find(|style| style.name().eq_ignore_ascii_case(s))
+            .ok_or(ParseStyleError(()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Style;
+
+    #[test]
+    fn from_str() {
+        assert_eq!(Ok(Style::Upper), "upper".parse());
+        assert_eq!(Ok(Style::Lower), "LOWER".parse());
+        assert_eq!(Ok(Style::Unicode), "Unicode".parse());
+        assert_eq!(Ok(Style::Clock), "clock".parse());
+        assert!("".parse::<Style>().is_err());
+        assert!(" upper".parse::<Style>().is_err());
+    }
+
+    #[test]
+    fn round_trip() {
+        for &style in &Style::ALL {
+            assert_eq!(Ok(style), style.to_string().parse());
+        }
+        assert_eq!(Style::Upper, Style::default());
+        assert_eq!("lower  ", format!("{:7}", Style::Lower));
+    }
+}
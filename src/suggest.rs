@@ -0,0 +1,102 @@
+use crate::{Roman, Style};
+
+/// The length of the longest canonical numeral, `MMMMDCCCLXXXVIII`.
+const MAX_LEN: usize = 16;
+
+/// The most single-character edits a suggestion may be from the input.
+const MAX_DISTANCE: usize = 2;
+
+/// Suggests the numeral `input` was most likely meant to be, for a hint such as
+/// "did you mean XIV?" after its parsing failed.
+///
+/// This is a free function rather than a method of [`Error`](crate::Error), since
+/// it needs only the input: any parse error, or none, may be paired with it. The
+/// suggestion is the canonical numeral fewest single-character insertions,
+/// deletions and substitutions away from `input`, ignoring case. Among numerals as
+/// close as any other, that nearest the value `input` parses to with
+/// [`FromStr`](core::str::FromStr) is chosen, if it parses at all, or else the
+/// smallest. Returns `None` if no numeral is within two edits, or if the input
+/// would have to be replaced outright.
+///
+/// ## Examples
+///
+/// ```
+/// use xvii::suggest;
+///
+/// assert_eq!(suggest("MCMXCIIX").unwrap().to_string(), "MCMXCIX");
+/// assert_eq!(suggest("MMXQV").unwrap().to_string(), "MMXIV");
+/// assert_eq!(suggest("hello"), None);
+/// ```
+pub fn suggest(input: &str) -> Option<Roman> {
+    let value = input.parse::<Roman>().map_or(0, Roman::value);
+
+    let mut chars = ['\0'; MAX_LEN + MAX_DISTANCE];
+    let mut len = 0;
+    for c in input.chars() {
+        *chars.get_mut(len)? = c.to_ascii_uppercase();
+        len += 1;
+    }
+    let input = &chars[..len];
+
+    let mut best: Option<((usize, u16), Roman)> = None;
+    for n in Roman::MIN.value()..=Roman::MAX.value() {
+        let n = Roman::new_const(n);
+        let (buf, n_len) = n.to_array(Style::Upper);
+        let d = distance(input, &buf[..n_len]);
+        let key = (d, n.value().abs_diff(value));
+        match best {
+            Some((best, _)) if best <= key => {}
+            _ if d <= MAX_DISTANCE && d < len => best = Some((key, n)),
+            _ => {}
+        }
+    }
+    best.map(|(_, n)| n)
+}
+
+/// The number of single-character edits turning `a` into `b`.
+fn distance(a: &[char], b: &[u8]) -> usize {
+    let mut row = [0; MAX_LEN + 1];
+    for (j, cell) in row.iter_mut().enumerate() {
+        *cell = j;
+    }
+
+    for (i, &ca) in a.iter().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+        for (j, &cb) in b.iter().enumerate() {
+            let above = row[j + 1];
+            row[j + 1] = (diagonal + usize::from(ca != char::from(cb)))
+                .min(above + 1)
+                .min(row[j] + 1);
+            diagonal = above;
+        }
+    }
+    row[b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::suggest;
+
+    fn suggested(s: &str) -> Option<String> {
+        suggest(s).map(|n| n.to_string())
+    }
+
+    #[test]
+    fn near_misses() {
+        assert_eq!(Some("MCMXCIX"), suggested("MCMXCIIX").as_deref());
+        assert_eq!(Some("XIII"), suggested("XIIIIV").as_deref());
+        assert_eq!(Some("XI"), suggested("XQI").as_deref());
+        assert_eq!(Some("XIV"), suggested("xIv").as_deref());
+        assert_eq!(Some("MMXIV"), suggested("MMXQV").as_deref());
+        assert_eq!(Some("XIV"), suggested("XIV").as_deref());
+    }
+
+    #[test]
+    fn far_misses() {
+        assert_eq!(None, suggested("Q"));
+        assert_eq!(None, suggested("QQQ"));
+        assert_eq!(None, suggested("hello"));
+        assert_eq!(None, suggested(&"M".repeat(20)));
+    }
+}
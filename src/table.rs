@@ -0,0 +1,161 @@
+use crate::{roman::ladder, Roman, Style};
+use core::{
+    fmt::{self, Display},
+    slice,
+};
+
+/// A column of numerals, right-aligned to the widest entry.
+///
+/// Roman numerals vary wildly in width (`C` is one character, `LXXXVIII` is eight),
+/// which makes them awkward to line up in tables and fixed-width reports. A `Column`
+/// measures its values once, without formatting them, and then pads each one on the
+/// left as it is written.
+///
+/// Formatting a `Column` writes every cell on its own line. Use [`cells`](Column::cells)
+/// to place the cells into lines of your own.
+///
+/// ## Examples
+///
+/// ```
+/// use xvii::{Column, Roman, Style};
+///
+/// let values: Vec<_> = [1, 8, 10].iter().map(|&n| Roman::new(n).unwrap()).collect();
+/// let column = Column::new(&values, Style::Upper);
+/// assert_eq!(column.width(), 4);
+/// assert_eq!(column.to_string(), "   I\nVIII\n   X\n");
+///
+/// for (cell, n) in column.cells().zip(&[1, 8, 10]) {
+///     println!("{} | {}", cell, n);
+/// }
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct Column<'a> {
+    values: &'a [Roman],
+    style: Style,
+    width: usize,
+}
+
+impl<'a> Column<'a> {
+    /// Creates a column of `values`, to be formatted in the given style.
+    pub fn new(values: &'a [Roman], style: Style) -> Self {
+        let width = values
+            .iter()
+            .map(|roman| ladder::formatted_chars(roman.value(), style))
+            .max()
+            .unwrap_or(0);
+
+        Column {
+            values,
+            style,
+            width,
+        }
+    }
+
+    /// Returns the width of the widest numeral in the column, in characters.
+    pub const fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Returns an iterator over the cells of the column, each padded to the column's width.
+    pub fn cells(&self) -> Cells<'a> {
+        Cells {
+            values: self.values.iter(),
+            style: self.style,
+            width: self.width,
+        }
+    }
+}
+
+impl Display for Column<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for cell in self.cells() {
+            writeln!(f, "{}", cell)?;
+        }
+        Ok(())
+    }
+}
+
+/// Iterator over the cells of a [`Column`].
+///
+/// This struct is created by the [`cells`](Column::cells) method.
+#[derive(Debug, Clone)]
+pub struct Cells<'a> {
+    values: slice::Iter<'a, Roman>,
+    style: Style,
+    width: usize,
+}
+
+impl Iterator for Cells<'_> {
+    type Item = Cell;
+
+    fn next(&mut self) -> Option<Cell> {
+        self.values.next().map(|&roman| Cell {
+            roman,
+            style: self.style,
+            width: self.width,
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.values.size_hint()
+    }
+}
+
+impl ExactSizeIterator for Cells<'_> {}
+
+/// A single numeral from a [`Column`], padded on the left to the column's width.
+#[derive(Debug, Clone, Copy)]
+pub struct Cell {
+    roman: Roman,
+    style: Style,
+    width: usize,
+}
+
+impl Display for Cell {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let len = ladder::formatted_chars(self.roman.value(), self.style);
+        for _ in len..self.width {
+            f.write_str(" ")?;
+        }
+        self.roman.format(self.style).fmt(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Column;
+    use crate::{roman::ladder, Roman, Style};
+
+    #[test]
+    fn formatted_len_matches_formatting() {
+        for n in 1..=4999 {
+            let roman = Roman::new(n).unwrap();
+            assert_eq!(roman.to_string().len(), ladder::formatted_len(n));
+            let unicode = roman.format(Style::Unicode).to_string();
+            assert_eq!(
+                unicode.chars().count(),
+                ladder::formatted_chars(n, Style::Unicode)
+            );
+        }
+    }
+
+    #[test]
+    fn empty_column() {
+        let column = Column::new(&[], Style::Upper);
+        assert_eq!(0, column.width());
+        assert_eq!("", column.to_string());
+    }
+
+    #[test]
+    fn lowercase_cells() {
+        let values = [Roman::new(4).unwrap(), Roman::new(1888).unwrap()];
+        let cells: Vec<_> = Column::new(&values, Style::Lower)
+            .cells()
+            .map(|cell| cell.to_string())
+            .collect();
+        assert_eq!(
+            vec![format!("{:>13}", "iv"), "mdccclxxxviii".to_owned()],
+            cells
+        );
+    }
+}
@@ -0,0 +1,48 @@
+use crate::{Roman, RomanFormatter};
+use ufmt::{uDisplay, uWrite, Formatter};
+
+impl uDisplay for RomanFormatter {
+    fn fmt<W>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error>
+    where
+        W: uWrite + ?Sized,
+    {
+        self.with_str(|s| f.write_str(s))
+    }
+}
+
+impl uDisplay for Roman {
+    fn fmt<W>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error>
+    where
+        W: uWrite + ?Sized,
+    {
+        let style = crate::global_style::display_style();
+
+        uDisplay::fmt(&self.format(style), f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Roman, Style};
+    use core::convert::Infallible;
+    use ufmt::{uWrite, uwrite};
+
+    struct Buf(String);
+
+    impl uWrite for Buf {
+        type Error = Infallible;
+
+        fn write_str(&mut self, s: &str) -> Result<(), Infallible> {
+            self.0.push_str(s);
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn matches_display() {
+        let n = Roman::new(1984).unwrap();
+        let mut buf = Buf(String::new());
+        uwrite!(buf, "{} {}", n, n.format(Style::Unicode)).unwrap();
+        assert_eq!(format!("{} {}", n, n.format(Style::Unicode)), buf.0);
+    }
+}
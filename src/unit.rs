@@ -1,23 +1,47 @@
 use crate::{Error, Result};
-use core::str;
+use core::{convert::TryFrom, ops::Sub, str};
+
+/// An unsigned integer type in which digit and unit values are accumulated.
+///
+/// Ordinary numerals never exceed `u16`, but extended notations have digits worth
+/// far more than that.
+pub trait UnitValue: Copy + Ord + Sub<Output = Self> + From<u16> {
+    fn checked_mul(self, rhs: Self) -> Option<Self>;
+    fn checked_add(self, rhs: Self) -> Option<Self>;
+}
+
+macro_rules! unit_value {
+    ($($t:ty),*) => {$(
+        impl UnitValue for $t {
+            fn checked_mul(self, rhs: Self) -> Option<Self> {
+                <$t>::checked_mul(self, rhs)
+            }
+
+            fn checked_add(self, rhs: Self) -> Option<Self> {
+                <$t>::checked_add(self, rhs)
+            }
+        }
+    )*};
+}
+
+unit_value!(u16, u32, u64);
 
 /// Accumulates the value of a single numeral "unit".
 ///
 /// `qty` represents the number of times the numeral has appeared in the unit, while `num` represents
 /// the numeric value of the numeral. Obviously, the final value of the unit is evaluated by
 /// multiplying these two.
-#[derive(Default)]
-struct Accumulator {
+struct Accumulator<T> {
     qty: u16,
-    val: u16,
+    val: T,
 }
 
-impl Accumulator {
-    fn new(val: u16) -> Self {
+impl<T: UnitValue> Accumulator<T> {
+    fn new(val: T) -> Self {
         Accumulator { qty: 1, val }
     }
 
-    fn push(mut self, val: u16) -> Option<PushResult> {
+    fn push(mut self, val: T) -> Option<PushResult<T>> {
         use core::cmp::Ordering::*;
 
         let res = match self.val.cmp(&val) {
@@ -33,8 +57,8 @@ impl Accumulator {
         Some(res)
     }
 
-    const fn value(&self) -> Option<u16> {
-        self.qty.checked_mul(self.val)
+    fn value(&self) -> Option<T> {
+        self.val.checked_mul(T::from(self.qty))
     }
 }
 
@@ -45,9 +69,9 @@ impl Accumulator {
 /// and so forth are all valid contents for an accumulator. However, changing the unit value
 /// will cause the accumulator to emit a complete result, signifying that a value should be
 /// produced by the iterator and a new accumulator created.
-enum PushResult {
-    Partial(Accumulator),
-    Complete(u16, Option<Accumulator>),
+enum PushResult<T> {
+    Partial(Accumulator<T>),
+    Complete(T, Option<Accumulator<T>>),
 }
 
 /// Iterates "units" of a Roman numeral.
@@ -57,36 +81,79 @@ enum PushResult {
 /// units. The first has a value of `9`, while the second is two values: `[10, 2]`.
 /// My theory is that this will allow me to calculate the value of a Roman numeral
 /// by reading from left to right just once.
-pub struct RomanUnitIterator<'a> {
-    bytes: str::Bytes<'a>,
-    acc: Option<Accumulator>,
+///
+/// The iterator works on digit values rather than characters, so that the same
+/// rules can be applied to any alphabet of digits.
+pub struct RomanUnitIterator<I, T = u16> {
+    digits: I,
+    acc: Option<Accumulator<T>>,
 }
 
-impl<'a> RomanUnitIterator<'a> {
-    pub fn new(s: &'a str) -> RomanUnitIterator<'a> {
-        RomanUnitIterator {
-            bytes: s.bytes(),
-            acc: None,
+/// The digit values of a numeral.
+///
+/// Besides the ASCII letters, the precomposed numerals of the Unicode Number Forms
+/// block (`Ⅰ` through `ⅿ`) are accepted. Those which stand for several letters,
+/// such as `Ⅻ`, yield each of their digits in turn.
+pub struct CharDigits<'a> {
+    chars: str::CharIndices<'a>,
+    pending: &'static [u16],
+}
+
+impl Iterator for CharDigits<'_> {
+    type Item = Result<u16>;
+
+    fn next(&mut self) -> Option<Result<u16>> {
+        if let Some((&digit, rest)) = self.pending.split_first() {
+            self.pending = rest;
+            return Some(Ok(digit));
+        }
+
+        let (offset, c) = self.chars.next()?;
+        if let Ok(u) = u8::try_from(c) {
+            if u.is_ascii() {
+                return Some(digit_at(u, offset));
+            }
+        }
+
+        match number_form(c) {
+            Some((&digit, rest)) => {
+                self.pending = rest;
+                Some(Ok(digit))
+            }
+            None => Some(Err(Error::InvalidChar(c, offset))),
         }
     }
 }
 
-impl<'a> Iterator for RomanUnitIterator<'a> {
-    type Item = Result<u16>;
+impl<'a> RomanUnitIterator<CharDigits<'a>> {
+    pub fn new(s: &'a str) -> Self {
+        RomanUnitIterator::from_digits(CharDigits {
+            chars: s.char_indices(),
+            pending: &[],
+        })
+    }
+}
+
+impl<T, I: Iterator<Item = Result<T>>> RomanUnitIterator<I, T> {
+    pub fn from_digits(digits: I) -> Self {
+        RomanUnitIterator { digits, acc: None }
+    }
+}
+
+impl<T: UnitValue, I: Iterator<Item = Result<T>>> Iterator for RomanUnitIterator<I, T> {
+    type Item = Result<T>;
 
     fn next(&mut self) -> Option<Self::Item> {
         loop {
-            let value = match self.bytes.next() {
+            let value = match self.digits.next() {
                 None => {
                     return self
                         .acc
                         .take()
                         .map(|acc| acc.value().ok_or(Error::Overflow))
                 }
-                Some(u) => match to_digit(u) {
-                    Ok(u) => u,
-                    Err(e) => return Some(Err(e)),
-                },
+                Some(Ok(u)) => u,
+                Some(Err(e)) => return Some(Err(e)),
             };
 
             match self.acc.take() {
@@ -104,32 +171,142 @@ impl<'a> Iterator for RomanUnitIterator<'a> {
     }
 }
 
-fn to_digit(u: u8) -> Result<u16> {
+/// Sums the units of a numeral without checking the result against the valid range.
+pub(crate) fn sum(s: &str) -> Result<u16> {
+    sum_units(RomanUnitIterator::new(s))
+}
+
+/// Sums the units of a numeral written in ASCII bytes, without checking the result
+/// against the valid range.
+///
+/// This follows the same rules as [`RomanUnitIterator`], but is written out by hand
+/// so that it can be evaluated at compile time.
+pub(crate) const fn sum_bytes(bytes: &[u8]) -> Result<u16> {
+    let mut total: u16 = 0;
+    // The digit being accumulated and how many times it has appeared.
+    let mut digit = 0;
+    let mut qty: u16 = 0;
+    let mut i = 0;
+
+    while i < bytes.len() {
+        let value = match digit_at(bytes[i], i) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        i += 1;
+
+        if qty == 0 {
+            digit = value;
+            qty = 1;
+            continue;
+        }
+        if value == digit {
+            qty += 1;
+            continue;
+        }
+
+        let run = match digit.checked_mul(qty) {
+            Some(run) => run,
+            None => return Err(Error::Overflow),
+        };
+        // A larger digit completes the unit by subtracting the run before it, while a
+        // smaller one completes it as it stands and starts the next.
+        let unit = if value > digit {
+            qty = 0;
+            value.checked_sub(run)
+        } else {
+            digit = value;
+            qty = 1;
+            Some(run)
+        };
+        total = match unit {
+            Some(unit) => match total.checked_add(unit) {
+                Some(total) => total,
+                None => return Err(Error::Overflow),
+            },
+            None => return Err(Error::Overflow),
+        };
+    }
+
+    match digit.checked_mul(qty) {
+        Some(run) => match total.checked_add(run) {
+            Some(total) => Ok(total),
+            None => Err(Error::Overflow),
+        },
+        None => Err(Error::Overflow),
+    }
+}
+
+/// Sums a sequence of unit values, failing if the total overflows.
+pub(crate) fn sum_units<T: UnitValue>(mut units: impl Iterator<Item = Result<T>>) -> Result<T> {
+    units.try_fold(T::from(0), |acc, r| {
+        r?.checked_add(acc).ok_or(Error::Overflow)
+    })
+}
+
+/// The digits of each numeral in U+2160..=U+216F, which U+2170..=U+217F repeat in
+/// lowercase.
+static NUMBER_FORMS: [&[u16]; 16] = [
+    &[1],
+    &[1, 1],
+    &[1, 1, 1],
+    &[1, 5],
+    &[5],
+    &[5, 1],
+    &[5, 1, 1],
+    &[5, 1, 1, 1],
+    &[1, 10],
+    &[10],
+    &[10, 1],
+    &[10, 1, 1],
+    &[50],
+    &[100],
+    &[500],
+    &[1000],
+];
+
+/// Returns the first digit of a precomposed Unicode numeral and those which follow it.
+pub(crate) fn number_form(c: char) -> Option<(&'static u16, &'static [u16])> {
+    match c {
+        '\u{2160}'..='\u{217f}' => NUMBER_FORMS[(c as usize - 0x2160) % 16].split_first(),
+        _ => None,
+    }
+}
+
+pub(crate) const fn to_digit(u: u8) -> Option<u16> {
     match u.to_ascii_lowercase() {
-        b'm' => Ok(1000),
-        b'd' => Ok(500),
-        b'c' => Ok(100),
-        b'l' => Ok(50),
-        b'x' => Ok(10),
-        b'v' => Ok(5),
-        b'i' => Ok(1),
+        b'm' => Some(1000),
+        b'd' => Some(500),
+        b'c' => Some(100),
+        b'l' => Some(50),
+        b'x' => Some(10),
+        b'v' => Some(5),
+        b'i' => Some(1),
+
+        _ => None,
+    }
+}
 
-        _ => Err(Error::InvalidDigit(u)),
+/// Reads the digit `u`, found at `offset` in a numeral.
+pub(crate) const fn digit_at(u: u8, offset: usize) -> Result<u16> {
+    match to_digit(u) {
+        Some(value) => Ok(value),
+        None => Err(Error::InvalidDigit(u, offset)),
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::RomanUnitIterator;
-    use crate::Roman;
+    use crate::{Error, Roman};
 
     #[test]
     fn to_digit_works() {
         let digits = b"mDcLxVi";
-        assert!(digits.iter().all(|&d| super::to_digit(d).is_ok()));
+        assert!(digits.iter().all(|&d| super::to_digit(d).is_some()));
 
         let digits = b"aBeFgH";
-        assert!(digits.iter().all(|&d| super::to_digit(d).is_err()));
+        assert!(digits.iter().all(|&d| super::to_digit(d).is_none()));
     }
 
     #[test]
@@ -149,6 +326,14 @@ mod tests {
         assert_eq!(9, "ix".parse::<Roman>().unwrap().value());
     }
 
+    #[test]
+    fn number_forms() {
+        assert_eq!(12, "Ⅻ".parse::<Roman>().unwrap().value());
+        assert_eq!(17, "ⅹⅶ".parse::<Roman>().unwrap().value());
+        assert_eq!(1984, "ⅯⅭⅯⅬⅩⅩⅩⅣ".parse::<Roman>().unwrap().value());
+        assert_eq!(Err(Error::InvalidChar('ↀ', 0)), "ↀ".parse::<Roman>());
+    }
+
     #[test]
     fn iiiiix_equals_5() {
         // Yes, I know this is stupid, but this is how units are meant to work.
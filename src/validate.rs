@@ -0,0 +1,288 @@
+use crate::{
+    parser::{Breach, Classical},
+    unit, Error, Result, Roman,
+};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::{
+    convert::TryFrom,
+    fmt::{self, Display},
+};
+
+/// A problem found in a numeral by [`validate`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Issue {
+    pub(crate) offset: usize,
+    pub(crate) kind: IssueKind,
+}
+
+impl Issue {
+    /// Returns the byte offset of the digit at which the problem was found.
+    pub const fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Returns the kind of problem found.
+    pub const fn kind(&self) -> IssueKind {
+        self.kind
+    }
+}
+
+impl Display for Issue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} (at byte {})", self.kind, self.offset)
+    }
+}
+
+/// The kinds of problem reported by [`validate`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum IssueKind {
+    /// The string is empty.
+    Empty,
+    /// A character which is not one of the seven ASCII digits, in either case.
+    InvalidChar(char),
+    /// A digit repeated too many times in a row: more than once for `V`, `L` and `D`,
+    /// more than three times for `I`, `X` and `C`, or more than four for `M`.
+    ExcessRepetition(char),
+    /// A smaller digit before a larger one other than the six classical pairs, or a
+    /// smaller digit repeated before a larger one, as in `IIX`.
+    InvalidPair(char, char),
+    /// A digit in a different case from the first.
+    MixedCase,
+    /// A digit, or subtractive pair, worth as much as or more than what came before
+    /// it, as in `IXI` or `LXL`.
+    NonDescending,
+}
+
+impl Display for IssueKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            IssueKind::Empty => f.write_str("Empty numeral"),
+            IssueKind::InvalidChar(c) => write!(f, "Invalid character: {}", c),
+            IssueKind::ExcessRepetition(c) => write!(f, "Digit repeated too many times: {}", c),
+            IssueKind::InvalidPair(smaller, larger) => {
+                write!(f, "Invalid subtractive pair: {}{}", smaller, larger)
+            }
+            IssueKind::MixedCase => f.write_str("Digit in a different case"),
+            IssueKind::NonDescending => f.write_str("Digits out of descending order"),
+        }
+    }
+}
+
+/// Checks a numeral against the rules of classical notation, reporting every
+/// problem found along with where it was found, in order of position.
+///
+/// A numeral with no issues is written exactly as this crate formats it, in either
+/// case. Unlike parsing, which stops at the first error, checking carries on past
+/// each problem, so that a form or a teaching tool can point them all out at once.
+/// Every character which is not an ASCII digit is reported as invalid, including the
+/// precomposed numerals of the Unicode Number Forms block.
+///
+/// ## Examples
+///
+/// ```
+/// use xvii::{validate, IssueKind};
+///
+/// assert!(validate("MCMLXXXIV").is_empty());
+///
+/// let issues = validate("XIIIIc");
+/// assert_eq!(issues.len(), 3);
+/// assert_eq!(issues[0].kind(), IssueKind::ExcessRepetition('I'));
+/// assert_eq!(issues[0].offset(), 4);
+/// assert_eq!(issues[1].kind(), IssueKind::InvalidPair('I', 'c'));
+/// assert_eq!(issues[2].kind(), IssueKind::MixedCase);
+/// ```
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub fn validate(s: &str) -> Vec<Issue> {
+    let mut issues = Vec::new();
+    check(s, |issue| issues.push(issue));
+
+    // A pair is reported at its first digit, which may put it out of order.
+    issues.sort_by_key(Issue::offset);
+    issues
+}
+
+impl Roman {
+    /// Parses a numeral only if it is written exactly as this crate formats it, in
+    /// either case.
+    ///
+    /// A string which `FromStr` would reject fails with the same error. One which it
+    /// would accept, but which is spelled some other way, fails with
+    /// [`Error::NonCanonical`], carrying its value and the first problem
+    /// [`validate`] would report, so that the canonical form can be offered instead.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use xvii::{Error, IssueKind, Roman};
+    ///
+    /// assert_eq!(Roman::parse_canonical("XIV").unwrap().value(), 14);
+    /// assert_eq!(Roman::parse_canonical("xiv").unwrap().value(), 14);
+    ///
+    /// match Roman::parse_canonical("XIIII") {
+    ///     Err(Error::NonCanonical { value, issue }) => {
+    ///         assert_eq!(issue.kind(), IssueKind::ExcessRepetition('I'));
+    ///         assert_eq!(Roman::new(value).unwrap().to_string(), "XIV");
+    ///     }
+    ///     _ => unreachable!(),
+    /// }
+    /// ```
+    pub fn parse_canonical(s: &str) -> Result<Roman> {
+        let n = s.parse::<Roman>()?;
+        let mut first: Option<Issue> = None;
+        check(s, |issue| match first {
+            Some(first) if first.offset <= issue.offset => {}
+            _ => first = Some(issue),
+        });
+
+        match first {
+            Some(issue) => Err(Error::NonCanonical {
+                value: n.value(),
+                issue,
+            }),
+            None => Ok(n),
+        }
+    }
+}
+
+/// Checks a numeral as described under [`validate`], passing on each problem as it
+/// is found.
+fn check(s: &str, mut report: impl FnMut(Issue)) {
+    let mut issue = |offset, kind| report(Issue { offset, kind });
+
+    if s.is_empty() {
+        issue(0, IssueKind::Empty);
+    }
+
+    let mut upper = None;
+    let mut previous = None;
+    let mut run = 0;
+    let mut classical = Classical::new();
+
+    for (offset, c) in s.char_indices() {
+        let value = match u8::try_from(c).ok().and_then(unit::to_digit) {
+            Some(value) => value,
+            None => {
+                issue(offset, IssueKind::InvalidChar(c));
+                continue;
+            }
+        };
+
+        if *upper.get_or_insert(c.is_ascii_uppercase()) != c.is_ascii_uppercase() {
+            issue(offset, IssueKind::MixedCase);
+        }
+
+        run = match previous {
+            Some(p) if p == value => run + 1,
+            _ => 1,
+        };
+        if run == max_run(value) + 1 {
+            issue(offset, IssueKind::ExcessRepetition(c));
+        }
+
+        match classical.next(value, (offset, c)) {
+            Some(Breach::InvalidPair((p_offset, p), (_, c))) => {
+                issue(p_offset, IssueKind::InvalidPair(p, c))
+            }
+            Some(Breach::PairAfterSmaller((p_offset, _))) => {
+                issue(p_offset, IssueKind::NonDescending)
+            }
+            Some(Breach::AfterPair(..)) => issue(offset, IssueKind::NonDescending),
+            None => {}
+        }
+
+        previous = Some(value);
+    }
+}
+
+/// The number of times a digit may be written in a row.
+fn max_run(value: u16) -> u16 {
+    match value {
+        1000 => 4,
+        1 | 10 | 100 => 3,
+        _ => 1,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(feature = "alloc")]
+    use super::validate;
+    use super::IssueKind;
+    use crate::{Error, Roman};
+
+    #[cfg(feature = "alloc")]
+    fn kinds(s: &str) -> Vec<(usize, IssueKind)> {
+        validate(s)
+            .into_iter()
+            .map(|issue| (issue.offset(), issue.kind()))
+            .collect()
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn canonical_numerals_have_no_issues() {
+        for n in 1..=4999 {
+            let n = Roman::new(n).unwrap();
+            assert_eq!(Vec::<(usize, IssueKind)>::new(), kinds(&n.to_string()));
+            assert!(validate(&n.to_lowercase()).is_empty());
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn order() {
+        assert_eq!(vec![(2, IssueKind::NonDescending)], kinds("IXI"));
+        assert_eq!(vec![(1, IssueKind::NonDescending)], kinds("LXL"));
+        assert_eq!(vec![(1, IssueKind::NonDescending)], kinds("VIV"));
+        assert_eq!(vec![(2, IssueKind::NonDescending)], kinds("CMD"));
+        assert_eq!(vec![(0, IssueKind::InvalidPair('V', 'X'))], kinds("VX"));
+        assert_eq!(vec![(1, IssueKind::InvalidPair('I', 'X'))], kinds("IIX"));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn repetition_and_characters() {
+        assert_eq!(vec![(1, IssueKind::ExcessRepetition('V'))], kinds("VV"));
+        assert_eq!(vec![(4, IssueKind::ExcessRepetition('M'))], kinds("MMMMM"));
+        assert_eq!(
+            vec![
+                (1, IssueKind::InvalidChar('Q')),
+                (3, IssueKind::InvalidChar('Ⅻ')),
+            ],
+            kinds("XQIⅫ")
+        );
+        assert_eq!(vec![(0, IssueKind::Empty)], kinds(""));
+    }
+
+    #[test]
+    fn parse_canonical() {
+        for n in 1..=4999 {
+            let n = Roman::new(n).unwrap();
+            assert_eq!(Ok(n), Roman::parse_canonical(&n.to_string()));
+        }
+
+        let kind = |s| match Roman::parse_canonical(s) {
+            Err(Error::NonCanonical { value, issue }) => {
+                Some((value, issue.offset(), issue.kind()))
+            }
+            _ => None,
+        };
+        assert_eq!(Some((4, 3, IssueKind::ExcessRepetition('I'))), kind("IIII"));
+        assert_eq!(Some((99, 0, IssueKind::InvalidPair('I', 'C'))), kind("IC"));
+        assert_eq!(Some((10, 2, IssueKind::NonDescending)), kind("IXI"));
+        assert_eq!(Some((14, 1, IssueKind::MixedCase)), kind("xIV"));
+        assert_eq!(Some((12, 0, IssueKind::InvalidChar('Ⅻ'))), kind("Ⅻ"));
+        assert_eq!(
+            Err(Error::InvalidDigit(b'Q', 1)),
+            Roman::parse_canonical("XQ")
+        );
+        assert_eq!(
+            Err(Error::OutOfRange(5000)),
+            Roman::parse_canonical("MMMMM")
+        );
+    }
+}
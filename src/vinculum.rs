@@ -0,0 +1,665 @@
+use crate::{
+    backend::{sealed::Sealed, RomanBackend},
+    roman::ladder,
+    unit::{self, RomanUnitIterator},
+    Error, GenericRoman, Result, Roman, RomanParser, Style,
+};
+use core::{
+    convert::TryFrom,
+    fmt::{self, Display, Write},
+    iter,
+    num::{NonZeroU32, NonZeroU64},
+    str::CharIndices,
+};
+
+/// Combining macron, the overline written by the formatter.
+pub(crate) const MACRON: char = '\u{304}';
+/// Combining overline, also accepted when parsing.
+const OVERLINE: char = '\u{305}';
+/// Combining double overline, which multiplies by a million.
+pub(crate) const DOUBLE_OVERLINE: char = '\u{33f}';
+
+/// A Roman numeral extended with the vinculum, an overline which multiplies a
+/// digit by one thousand.
+///
+/// `Roman32` covers the range `1..=4_999_999`. Values up to 4999 are written
+/// exactly as a [`Roman`] would write them. Beyond that, the thousands are written
+/// as a numeral of their own with an overline on every digit, followed by the
+/// remainder as a [`Roman`] would write it, so that 5000 is `V̄` and 1,234,567 is
+/// `M̄C̄C̄X̄X̄X̄ĪV̄DLXVII`. Overlines are combining characters following each digit:
+/// U+0304 when formatting, either U+0304 or U+0305 when parsing. Parsing accepts
+/// the precomposed numerals of the Unicode Number Forms block, as `FromStr` for
+/// [`Roman`] does, so that every style reads back.
+///
+/// ## Examples
+///
+/// ```
+/// use xvii::Roman32;
+///
+/// let n = Roman32::new(10_017).unwrap();
+/// assert_eq!(n.to_string(), "X\u{304}XVII");
+/// assert_eq!("X\u{304}XVII".parse::<Roman32>().unwrap(), n);
+/// assert_eq!("MMXXIV".parse::<Roman32>().unwrap().value(), 2024);
+/// ```
+pub type Roman32 = GenericRoman<u32>;
+
+impl Roman32 {
+    /// The length in bytes of the longest `Roman32` in any [`Style`]: 4,888,888 in
+    /// Unicode style, where each overlined digit takes five bytes.
+    pub const MAX_FORMATTED_LEN: usize = 107;
+
+    /// Creates a `Roman32` value based on a [`u32`].
+    ///
+    /// Returns [`Error::OutOfRange`] for zero, as [`Roman::new`] does, and
+    /// [`Error::Overflow`] for values above 4,999,999.
+    pub const fn new(n: u32) -> Result<Roman32> {
+        match NonZeroU32::new(n) {
+            None => Err(Error::OutOfRange(0)),
+            Some(n) if n.get() <= 4_999_999 => Ok(GenericRoman(n)),
+            Some(_) => Err(Error::Overflow),
+        }
+    }
+
+    /// Returns a [`Roman32Formatter`] which lazily formats `self` as a lowercase or
+    /// uppercase numeral depending on `style`.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use xvii::{Roman32, Style};
+    ///
+    /// let n = Roman32::new(6_001).unwrap();
+    /// assert_eq!(n.format(Style::Lower).to_string(), "v\u{304}i\u{304}i");
+    /// ```
+    pub const fn format(self, style: Style) -> Roman32Formatter {
+        Roman32Formatter { style, value: self }
+    }
+
+    /// Returns the value of this numeral.
+    pub const fn value(self) -> u32 {
+        self.0.get()
+    }
+
+    /// Returns the inner value.
+    pub const fn into_inner(self) -> NonZeroU32 {
+        self.0
+    }
+}
+
+impl From<Roman> for Roman32 {
+    fn from(n: Roman) -> Self {
+        GenericRoman(n.into_inner().into())
+    }
+}
+
+impl TryFrom<Roman32> for Roman {
+    type Error = Error;
+
+    fn try_from(n: Roman32) -> Result<Self> {
+        u16::try_from(n.value())
+            .map_err(|_| Error::Overflow)
+            .and_then(Roman::new)
+    }
+}
+
+/// Lazy formatter for [`Roman32`] values.
+///
+/// This struct is created by the [`format`](Roman32::format) method.
+#[derive(Debug, Copy, Clone)]
+pub struct Roman32Formatter {
+    style: Style,
+    value: Roman32,
+}
+
+impl Display for Roman32Formatter {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write_levels(f, self.value.value().into(), self.style, Notation::Vinculum)
+    }
+}
+
+/// How digits multiplied by a thousand are marked.
+#[derive(Copy, Clone)]
+enum Notation {
+    Vinculum,
+    Parentheses,
+}
+
+/// Writes `value` with as few levels of multiplication as possible, giving each level
+/// the thousands, or millions, it multiplies.
+fn write_levels(
+    f: &mut fmt::Formatter,
+    value: u64,
+    style: Style,
+    notation: Notation,
+) -> fmt::Result {
+    let top = match value {
+        0..=4999 => 0,
+        5000..=4_999_999 => 1,
+        _ => 2,
+    };
+
+    for level in (0..=top).rev() {
+        let scale = 1000_u64.pow(level);
+        let part = match level {
+            _ if level == top => value / scale,
+            _ => value / scale % 1000,
+        };
+        if part == 0 {
+            continue;
+        }
+
+        // Every part is below 5000, so fits the ordinary ladder, and the last is
+        // written just as a `Roman` would write it.
+        match (notation, level) {
+            (_, 0) => write!(f, "{}", Roman::new_const(part as u16).format(style))?,
+            (Notation::Vinculum, 1) => write_digits(f, part as u16, style, Some(MACRON))?,
+            (Notation::Vinculum, _) => write_digits(f, part as u16, style, Some(DOUBLE_OVERLINE))?,
+            (Notation::Parentheses, _) => {
+                for _ in 0..level {
+                    f.write_char('(')?;
+                }
+                write_digits(f, part as u16, style, None)?;
+                for _ in 0..level {
+                    f.write_char(')')?;
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn write_digits(
+    f: &mut fmt::Formatter,
+    mut value: u16,
+    style: Style,
+    mark: Option<char>,
+) -> fmt::Result {
+    for entry in ladder::VALUES {
+        while value >= entry.value {
+            for c in entry.digits(style).chars() {
+                f.write_char(c)?;
+                if let Some(mark) = mark {
+                    f.write_char(mark)?;
+                }
+            }
+            value -= entry.value;
+        }
+    }
+
+    Ok(())
+}
+
+impl Sealed for u32 {}
+
+impl RomanBackend for u32 {
+    type NonZero = NonZeroU32;
+
+    const MAX: u32 = 4_999_999;
+    const NAME: &'static str = "Roman32";
+
+    fn new(n: u32) -> Result<Roman32> {
+        Roman32::new(n)
+    }
+
+    fn get(n: Roman32) -> u32 {
+        n.value()
+    }
+
+    fn fmt(n: Roman32, style: Style, f: &mut fmt::Formatter) -> fmt::Result {
+        Display::fmt(&n.format(style), f)
+    }
+
+    /// Parses a numeral in either case, in which overlined digits must all come
+    /// before ordinary ones, as [`RomanParser::parse32`] does.
+    fn parse(s: &str) -> Result<Roman32> {
+        let value = RomanParser::new().sum(s, 1)?;
+        u32::try_from(value)
+            .map_err(|_| Error::Overflow)
+            .and_then(Roman32::new)
+    }
+}
+
+/// Sums digits which are each multiplied by a thousand for every level given with
+/// them, up to `max_level`, along with their offsets. Digits must come in descending
+/// order of level.
+fn parse_levels(digits: impl Iterator<Item = (u32, usize, char)>, max_level: u32) -> Result<u64> {
+    let mut digits = digits.peekable();
+    let mut previous = None;
+    let mut total: u64 = 0;
+
+    for level in (0..=max_level).rev() {
+        let group = iter::from_fn(|| {
+            let (_, offset, c) = digits.next_if(|&(l, _, _)| l == level)?;
+            previous = Some((offset, c));
+            Some(digit(c, offset))
+        });
+        let sum = unit::sum_units(RomanUnitIterator::from_digits(group))?;
+        total += u64::from(sum) * 1000_u64.pow(level);
+    }
+
+    // Anything left either has too many levels or follows a digit with fewer, in
+    // which case it is the earlier digit which is missing its mark.
+    match digits.next() {
+        Some((_, offset, c)) => {
+            let (offset, c) = previous.unwrap_or((offset, c));
+            Err(Error::InvalidChar(c, offset))
+        }
+        None => Ok(total),
+    }
+}
+
+/// Returns the number of overlines a combining character stands for, if any.
+pub(crate) fn mark_level(c: char) -> Option<u32> {
+    match c {
+        MACRON | OVERLINE => Some(1),
+        DOUBLE_OVERLINE => Some(2),
+        _ => None,
+    }
+}
+
+/// Iterates the characters of a numeral along with the number of parentheses
+/// enclosing each and their offsets.
+struct Parens<'a> {
+    chars: CharIndices<'a>,
+    depth: u32,
+    /// The offset of the outermost parenthesis still open.
+    opened: usize,
+}
+
+impl Iterator for Parens<'_> {
+    type Item = (u32, usize, char);
+
+    fn next(&mut self) -> Option<(u32, usize, char)> {
+        loop {
+            match self.chars.next()? {
+                (offset, '(') => {
+                    if self.depth == 0 {
+                        self.opened = offset;
+                    }
+                    self.depth += 1;
+                }
+                (_, ')') if self.depth > 0 => self.depth -= 1,
+                (offset, c) => return Some((self.depth, offset, c)),
+            }
+        }
+    }
+}
+
+fn parse_parens(s: &str, max_level: u32) -> Result<u64> {
+    let mut parens = Parens {
+        chars: s.char_indices(),
+        depth: 0,
+        opened: 0,
+    };
+    let value = parse_levels(&mut parens, max_level)?;
+    match parens.depth {
+        0 => Ok(value),
+        _ => Err(Error::InvalidDigit(b'(', parens.opened)),
+    }
+}
+
+impl Roman32 {
+    /// Returns a [`Parenthesized`] which lazily formats `self` in the plain-text
+    /// convention where parentheses multiply by a thousand, so that 5000 is `(V)`.
+    ///
+    /// The digits are grouped as in vinculum notation, with one pair of parentheses
+    /// around all of the thousands.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use xvii::{Roman32, Style};
+    ///
+    /// let n = Roman32::new(11_017).unwrap();
+    /// assert_eq!(n.parenthesized(Style::Upper).to_string(), "(XI)XVII");
+    /// ```
+    pub const fn parenthesized(self, style: Style) -> Parenthesized {
+        Parenthesized {
+            value: self.value() as u64,
+            style,
+        }
+    }
+
+    /// Parses a numeral in which parentheses multiply by a thousand.
+    ///
+    /// Each digit may be enclosed separately or together with others, so `(X)(I)` and
+    /// `(XI)` both stand for 11,000. Parenthesized digits must come before ordinary
+    /// ones.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use xvii::Roman32;
+    ///
+    /// assert_eq!(Roman32::from_parenthesized("(X)(I)XVII").unwrap().value(), 11_017);
+    /// ```
+    pub fn from_parenthesized(s: &str) -> Result<Roman32> {
+        u32::try_from(parse_parens(s, 1)?)
+            .map_err(|_| Error::Overflow)
+            .and_then(Roman32::new)
+    }
+}
+
+/// A Roman numeral extended with single and double vinculum, the latter
+/// multiplying a digit by one million.
+///
+/// `RomanLarge` covers the range `1..=4_999_999_999`. Values below 5,000,000 are
+/// written exactly as a [`Roman32`] would write them. Beyond that, the millions are
+/// written with a double overline (U+033F) on every digit, followed by the
+/// thousands with a single overline and then the remainder, so that 5,000,000 is
+/// `V̿` and 12,345,678 is `X̿I̿I̿C̄C̄C̄X̄L̄V̄DCLXXVIII`. When parsing, two single
+/// overlines may stand in for a double one.
+///
+/// ## Examples
+///
+/// ```
+/// use xvii::RomanLarge;
+///
+/// let n = RomanLarge::new(2_000_000_017).unwrap();
+/// assert_eq!(n.to_string(), "M\u{33f}M\u{33f}XVII");
+/// assert_eq!("M\u{305}\u{305}M\u{33f}XVII".parse::<RomanLarge>().unwrap(), n);
+/// ```
+pub type RomanLarge = GenericRoman<u64>;
+
+impl RomanLarge {
+    /// The length in bytes of the longest `RomanLarge` in any [`Style`]:
+    /// 4,888,888,888 in Unicode style.
+    pub const MAX_FORMATTED_LEN: usize = 167;
+
+    /// Creates a `RomanLarge` value based on a [`u64`].
+    ///
+    /// Returns [`Error::OutOfRange`] for zero, as [`Roman::new`] does, and
+    /// [`Error::Overflow`] for values above 4,999,999,999.
+    pub const fn new(n: u64) -> Result<RomanLarge> {
+        match NonZeroU64::new(n) {
+            None => Err(Error::OutOfRange(0)),
+            Some(n) if n.get() <= 4_999_999_999 => Ok(GenericRoman(n)),
+            Some(_) => Err(Error::Overflow),
+        }
+    }
+
+    /// Returns a [`RomanLargeFormatter`] which lazily formats `self` as a lowercase
+    /// or uppercase numeral depending on `style`.
+    pub const fn format(self, style: Style) -> RomanLargeFormatter {
+        RomanLargeFormatter { style, value: self }
+    }
+
+    /// Returns the value of this numeral.
+    pub const fn value(self) -> u64 {
+        self.0.get()
+    }
+
+    /// Returns the inner value.
+    pub const fn into_inner(self) -> NonZeroU64 {
+        self.0
+    }
+}
+
+impl From<Roman> for RomanLarge {
+    fn from(n: Roman) -> Self {
+        GenericRoman(n.into_inner().into())
+    }
+}
+
+impl From<Roman32> for RomanLarge {
+    fn from(n: Roman32) -> Self {
+        GenericRoman(n.into_inner().into())
+    }
+}
+
+impl TryFrom<RomanLarge> for Roman32 {
+    type Error = Error;
+
+    fn try_from(n: RomanLarge) -> Result<Self> {
+        u32::try_from(n.value())
+            .map_err(|_| Error::Overflow)
+            .and_then(Roman32::new)
+    }
+}
+
+/// Lazy formatter for [`RomanLarge`] values.
+///
+/// This struct is created by the [`format`](RomanLarge::format) method.
+#[derive(Debug, Copy, Clone)]
+pub struct RomanLargeFormatter {
+    style: Style,
+    value: RomanLarge,
+}
+
+impl Display for RomanLargeFormatter {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write_levels(f, self.value.value(), self.style, Notation::Vinculum)
+    }
+}
+
+impl RomanLarge {
+    /// Returns a [`Parenthesized`] which lazily formats `self` in the plain-text
+    /// convention where parentheses multiply by a thousand, as described under
+    /// [`Roman32::parenthesized`]. Millions are enclosed in double parentheses.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use xvii::{RomanLarge, Style};
+    ///
+    /// let n = RomanLarge::new(5_000_001).unwrap();
+    /// assert_eq!(n.parenthesized(Style::Upper).to_string(), "((V))I");
+    /// ```
+    pub const fn parenthesized(self, style: Style) -> Parenthesized {
+        Parenthesized {
+            value: self.value(),
+            style,
+        }
+    }
+
+    /// Parses a numeral in which parentheses multiply by a thousand, as described
+    /// under [`Roman32::from_parenthesized`].
+    pub fn from_parenthesized(s: &str) -> Result<RomanLarge> {
+        RomanLarge::new(parse_parens(s, 2)?)
+    }
+}
+
+/// Lazy formatter for numerals in parenthesis notation.
+///
+/// This struct is created by the [`parenthesized`](Roman32::parenthesized) methods
+/// of [`Roman32`] and [`RomanLarge`].
+#[derive(Debug, Copy, Clone)]
+pub struct Parenthesized {
+    value: u64,
+    style: Style,
+}
+
+impl Display for Parenthesized {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write_levels(f, self.value, self.style, Notation::Parentheses)
+    }
+}
+
+impl Sealed for u64 {}
+
+impl RomanBackend for u64 {
+    type NonZero = NonZeroU64;
+
+    const MAX: u64 = 4_999_999_999;
+    const NAME: &'static str = "RomanLarge";
+
+    fn new(n: u64) -> Result<RomanLarge> {
+        RomanLarge::new(n)
+    }
+
+    fn get(n: RomanLarge) -> u64 {
+        n.value()
+    }
+
+    fn fmt(n: RomanLarge, style: Style, f: &mut fmt::Formatter) -> fmt::Result {
+        Display::fmt(&n.format(style), f)
+    }
+
+    /// Parses a numeral in either case, in which doubly overlined digits must come
+    /// first, then singly overlined ones, then ordinary ones.
+    fn parse(s: &str) -> Result<RomanLarge> {
+        RomanLarge::new(RomanParser::new().sum(s, 2)?)
+    }
+}
+
+fn digit(c: char, offset: usize) -> Result<u16> {
+    match u8::try_from(c) {
+        Ok(u) if u.is_ascii() => unit::digit_at(u, offset),
+        _ => Err(Error::InvalidChar(c, offset)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Roman32, RomanLarge};
+    use crate::{Error, Roman, Style};
+    use core::convert::TryFrom;
+
+    fn format(n: u32) -> String {
+        Roman32::new(n).unwrap().to_string()
+    }
+
+    #[test]
+    fn small_values_match_roman() {
+        for n in 1..=4999 {
+            let roman = Roman::new(n).unwrap();
+            let n32 = Roman32::from(roman);
+            for &style in &Style::ALL {
+                let s = n32.format(style).to_string();
+                assert_eq!(roman.format(style).to_string(), s);
+                assert_eq!(Ok(n32), s.parse());
+            }
+        }
+    }
+
+    #[test]
+    fn formats_thousands_with_overlines() {
+        assert_eq!("V\u{304}", format(5000));
+        assert_eq!("M\u{304}", format(1_000_000));
+        assert_eq!(
+            "M\u{304}C\u{304}C\u{304}X\u{304}X\u{304}X\u{304}I\u{304}V\u{304}DLXVII",
+            format(1_234_567)
+        );
+    }
+
+    #[test]
+    fn round_trips() {
+        for &n in &[5000, 5001, 10_999, 15_012, 123_456, 4_999_999] {
+            let n = Roman32::new(n).unwrap();
+            for &style in &Style::ALL {
+                assert_eq!(Ok(n), n.format(style).to_string().parse());
+            }
+        }
+        let large = RomanLarge::new(4_999_999_999).unwrap();
+        for &style in &Style::ALL {
+            assert_eq!(Ok(large), large.format(style).to_string().parse());
+        }
+        assert_eq!(Ok(12), "ⅩⅠⅠ".parse().map(Roman32::value));
+        assert_eq!(Ok(5000), "v\u{305}".parse().map(Roman32::value));
+    }
+
+    #[test]
+    fn max_formatted_len() {
+        let styles = [Style::Lower, Style::Upper, Style::Unicode, Style::Clock];
+        let n = Roman32::new(4_888_888).unwrap();
+        let large = RomanLarge::new(4_888_888_888).unwrap();
+        for &style in &styles {
+            assert!(n.format(style).to_string().len() <= Roman32::MAX_FORMATTED_LEN);
+            assert!(large.format(style).to_string().len() <= RomanLarge::MAX_FORMATTED_LEN);
+        }
+        assert_eq!(
+            Roman32::MAX_FORMATTED_LEN,
+            n.format(Style::Unicode).to_string().len()
+        );
+        assert_eq!(
+            RomanLarge::MAX_FORMATTED_LEN,
+            large.format(Style::Unicode).to_string().len()
+        );
+    }
+
+    #[test]
+    fn rejects_bad_input() {
+        assert_eq!(
+            Err(Error::InvalidDigit(b'V', 1)),
+            "MV\u{304}".parse::<Roman32>()
+        );
+        assert_eq!(
+            Err(Error::InvalidChar('\u{304}', 0)),
+            "\u{304}".parse::<Roman32>()
+        );
+        assert_eq!(Err(Error::OutOfRange(0)), "".parse::<Roman32>());
+        assert_eq!(Err(Error::OutOfRange(0)), "".parse::<RomanLarge>());
+        assert_eq!(Err(Error::OutOfRange(0)), Roman32::new(0));
+        assert_eq!(Err(Error::Overflow), Roman32::new(5_000_000));
+    }
+
+    #[test]
+    fn converts_to_roman() {
+        let n = Roman::new(1984).unwrap();
+        assert_eq!(Ok(n), Roman::try_from(Roman32::from(n)));
+        assert_eq!(
+            Err(Error::OutOfRange(5000)),
+            Roman::try_from(Roman32::new(5000).unwrap())
+        );
+    }
+
+    #[test]
+    fn large_values() {
+        let format = |n| RomanLarge::new(n).unwrap().to_string();
+        assert_eq!("V\u{33f}", format(5_000_000));
+        assert_eq!(
+            "X\u{33f}I\u{33f}I\u{33f}C\u{304}C\u{304}C\u{304}X\u{304}L\u{304}V\u{304}DCLXXVIII",
+            format(12_345_678)
+        );
+        assert_eq!(
+            Roman32::new(4_999_999).unwrap().to_string(),
+            format(4_999_999)
+        );
+
+        for &n in &[1, 5_000_001, 999_999_999, 4_999_999_999] {
+            assert_eq!(Ok(n), format(n).parse().map(RomanLarge::value));
+        }
+        assert_eq!(
+            Err(Error::InvalidDigit(b'V', 0)),
+            "V\u{33f}".parse::<Roman32>()
+        );
+        assert_eq!(Err(Error::Overflow), RomanLarge::new(5_000_000_000));
+    }
+
+    #[test]
+    fn parentheses() {
+        let format = |n| {
+            RomanLarge::new(n)
+                .unwrap()
+                .parenthesized(Style::Lower)
+                .to_string()
+        };
+        assert_eq!("(v)", format(5000));
+        assert_eq!("((xii))(cccxlv)dclxxviii", format(12_345_678));
+        assert_eq!("((v))i", format(5_000_001));
+
+        for &n in &[1, 5_000, 999_999_999, 4_999_999_999] {
+            assert_eq!(
+                Ok(n),
+                RomanLarge::from_parenthesized(&format(n)).map(RomanLarge::value)
+            );
+        }
+        assert_eq!(
+            Ok(5_011_000),
+            RomanLarge::from_parenthesized("((V))(X)(I)").map(RomanLarge::value)
+        );
+        assert_eq!(
+            Err(Error::InvalidDigit(b'(', 0)),
+            Roman32::from_parenthesized("(V")
+        );
+        assert_eq!(
+            Err(Error::InvalidDigit(b')', 1)),
+            Roman32::from_parenthesized("V)")
+        );
+        assert_eq!(
+            Err(Error::InvalidChar('V', 2)),
+            Roman32::from_parenthesized("((V))")
+        );
+    }
+}
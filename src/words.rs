@@ -0,0 +1,503 @@
+use crate::Roman;
+use core::fmt::{self, Display};
+
+/// A language in which the value of a numeral can be spelled out.
+///
+/// Variants are named for their ISO 639-1 codes.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum Language {
+    /// English, e.g. "one thousand nine hundred eighty-four".
+    En,
+    /// Latin, e.g. "mille nongenti octoginta quattuor".
+    La,
+    /// Spanish, e.g. "mil novecientos ochenta y cuatro".
+    Es,
+    /// French, e.g. "mille neuf cent quatre-vingt-quatre".
+    Fr,
+    /// German, e.g. "eintausendneunhundertvierundachtzig".
+    De,
+}
+
+static ONES: [&str; 20] = [
+    "",
+    "one",
+    "two",
+    "three",
+    "four",
+    "five",
+    "six",
+    "seven",
+    "eight",
+    "nine",
+    "ten",
+    "eleven",
+    "twelve",
+    "thirteen",
+    "fourteen",
+    "fifteen",
+    "sixteen",
+    "seventeen",
+    "eighteen",
+    "nineteen",
+];
+
+static TENS: [&str; 10] = [
+    "", "", "twenty", "thirty", "forty", "fifty", "sixty", "seventy", "eighty", "ninety",
+];
+
+static LA_ONES: [&str; 18] = [
+    "",
+    "unus",
+    "duo",
+    "tres",
+    "quattuor",
+    "quinque",
+    "sex",
+    "septem",
+    "octo",
+    "novem",
+    "decem",
+    "undecim",
+    "duodecim",
+    "tredecim",
+    "quattuordecim",
+    "quindecim",
+    "sedecim",
+    "septendecim",
+];
+
+static LA_TENS: [&str; 10] = [
+    "",
+    "decem",
+    "viginti",
+    "triginta",
+    "quadraginta",
+    "quinquaginta",
+    "sexaginta",
+    "septuaginta",
+    "octoginta",
+    "nonaginta",
+];
+
+static LA_HUNDREDS: [&str; 10] = [
+    "",
+    "centum",
+    "ducenti",
+    "trecenti",
+    "quadringenti",
+    "quingenti",
+    "sescenti",
+    "septingenti",
+    "octingenti",
+    "nongenti",
+];
+
+/// Neuter forms, agreeing with `milia`.
+static LA_THOUSANDS: [&str; 5] = ["", "", "duo", "tria", "quattuor"];
+
+static ES_ONES: [&str; 30] = [
+    "",
+    "uno",
+    "dos",
+    "tres",
+    "cuatro",
+    "cinco",
+    "seis",
+    "siete",
+    "ocho",
+    "nueve",
+    "diez",
+    "once",
+    "doce",
+    "trece",
+    "catorce",
+    "quince",
+    "dieciséis",
+    "diecisiete",
+    "dieciocho",
+    "diecinueve",
+    "veinte",
+    "veintiuno",
+    "veintidós",
+    "veintitrés",
+    "veinticuatro",
+    "veinticinco",
+    "veintiséis",
+    "veintisiete",
+    "veintiocho",
+    "veintinueve",
+];
+
+static ES_TENS: [&str; 10] = [
+    "",
+    "",
+    "veinte",
+    "treinta",
+    "cuarenta",
+    "cincuenta",
+    "sesenta",
+    "setenta",
+    "ochenta",
+    "noventa",
+];
+
+static ES_HUNDREDS: [&str; 10] = [
+    "",
+    "ciento",
+    "doscientos",
+    "trescientos",
+    "cuatrocientos",
+    "quinientos",
+    "seiscientos",
+    "setecientos",
+    "ochocientos",
+    "novecientos",
+];
+
+static FR_ONES: [&str; 20] = [
+    "", "un", "deux", "trois", "quatre", "cinq", "six", "sept", "huit", "neuf", "dix", "onze",
+    "douze", "treize", "quatorze", "quinze", "seize", "dix-sept", "dix-huit", "dix-neuf",
+];
+
+static FR_TENS: [&str; 7] = [
+    "",
+    "",
+    "vingt",
+    "trente",
+    "quarante",
+    "cinquante",
+    "soixante",
+];
+
+static DE_ONES: [&str; 20] = [
+    "",
+    "eins",
+    "zwei",
+    "drei",
+    "vier",
+    "fünf",
+    "sechs",
+    "sieben",
+    "acht",
+    "neun",
+    "zehn",
+    "elf",
+    "zwölf",
+    "dreizehn",
+    "vierzehn",
+    "fünfzehn",
+    "sechzehn",
+    "siebzehn",
+    "achtzehn",
+    "neunzehn",
+];
+
+/// Forms used as the first element of a compound, as in `einhundert`.
+static DE_PREFIXES: [&str; 10] = [
+    "", "ein", "zwei", "drei", "vier", "fünf", "sechs", "sieben", "acht", "neun",
+];
+
+static DE_TENS: [&str; 10] = [
+    "", "", "zwanzig", "dreißig", "vierzig", "fünfzig", "sechzig", "siebzig", "achtzig", "neunzig",
+];
+
+impl Roman {
+    /// Returns a [`Words`] which lazily spells out the value of a numeral in English.
+    ///
+    /// This is equivalent to `to_words(Language::En)`.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use xvii::Roman;
+    ///
+    /// assert_eq!(Roman::new(17).unwrap().words().to_string(), "seventeen");
+    /// assert_eq!(
+    ///     Roman::new(1984).unwrap().words().to_string(),
+    ///     "one thousand nine hundred eighty-four"
+    /// );
+    /// ```
+    pub const fn words(self) -> Words {
+        self.to_words(Language::En)
+    }
+
+    /// Returns a [`Words`] which lazily spells out the value of a numeral in the given
+    /// language.
+    ///
+    /// Values are spelled as cardinal numbers standing alone, so that one is "uno" in
+    /// Spanish and "eins" in German, and Latin forms are masculine.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use xvii::{Language, Roman};
+    ///
+    /// let seventeen = Roman::new(17).unwrap();
+    /// assert_eq!(seventeen.to_words(Language::Es).to_string(), "diecisiete");
+    /// assert_eq!(seventeen.to_words(Language::La).to_string(), "septendecim");
+    /// assert_eq!(
+    ///     Roman::new(80).unwrap().to_words(Language::Fr).to_string(),
+    ///     "quatre-vingts"
+    /// );
+    /// ```
+    pub const fn to_words(self, language: Language) -> Words {
+        Words {
+            value: self.value(),
+            language,
+        }
+    }
+}
+
+/// Lazy formatter spelling out the value of a numeral as words.
+///
+/// This struct is created by the [`words`](Roman::words) and
+/// [`to_words`](Roman::to_words) methods.
+#[derive(Debug, Copy, Clone)]
+pub struct Words {
+    value: u16,
+    language: Language,
+}
+
+impl Display for Words {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let thousands = usize::from(self.value / 1000);
+        let hundreds = usize::from(self.value / 100 % 10);
+        let rest = usize::from(self.value % 100);
+
+        match self.language {
+            Language::En => english(f, thousands, hundreds, rest),
+            Language::La => latin(f, thousands, hundreds, rest),
+            Language::Es => spanish(f, thousands, hundreds, rest),
+            Language::Fr => french(f, thousands, hundreds, rest),
+            Language::De => german(f, thousands, hundreds, rest),
+        }
+    }
+}
+
+fn english(f: &mut fmt::Formatter, thousands: usize, hundreds: usize, rest: usize) -> fmt::Result {
+    let mut separator = "";
+    if thousands > 0 {
+        write!(f, "{} thousand", ONES[thousands])?;
+        separator = " ";
+    }
+
+    if hundreds > 0 {
+        write!(f, "{}{} hundred", separator, ONES[hundreds])?;
+        separator = " ";
+    }
+
+    let (tens, ones) = (rest / 10, rest % 10);
+    match rest {
+        0 => Ok(()),
+        1..=19 => write!(f, "{}{}", separator, ONES[rest]),
+        _ if ones == 0 => write!(f, "{}{}", separator, TENS[tens]),
+        _ => write!(f, "{}{}-{}", separator, TENS[tens], ONES[ones]),
+    }
+}
+
+fn latin(f: &mut fmt::Formatter, thousands: usize, hundreds: usize, rest: usize) -> fmt::Result {
+    let mut separator = "";
+    match thousands {
+        0 => {}
+        1 => {
+            f.write_str("mille")?;
+            separator = " ";
+        }
+        _ => {
+            write!(f, "{} milia", LA_THOUSANDS[thousands])?;
+            separator = " ";
+        }
+    }
+
+    if hundreds > 0 {
+        write!(f, "{}{}", separator, LA_HUNDREDS[hundreds])?;
+        separator = " ";
+    }
+
+    // Eights and nines below ninety are counted down from the next ten, as in
+    // `duodeviginti` and `undeviginti`.
+    let (tens, ones) = (rest / 10, rest % 10);
+    match rest {
+        0 => Ok(()),
+        1..=17 => write!(f, "{}{}", separator, LA_ONES[rest]),
+        _ if ones == 8 && tens < 9 => write!(f, "{}duode{}", separator, LA_TENS[tens + 1]),
+        _ if ones == 9 && tens < 9 => write!(f, "{}unde{}", separator, LA_TENS[tens + 1]),
+        _ if ones == 0 => write!(f, "{}{}", separator, LA_TENS[tens]),
+        _ => write!(f, "{}{} {}", separator, LA_TENS[tens], LA_ONES[ones]),
+    }
+}
+
+fn spanish(f: &mut fmt::Formatter, thousands: usize, hundreds: usize, rest: usize) -> fmt::Result {
+    let mut separator = "";
+    match thousands {
+        0 => {}
+        1 => {
+            f.write_str("mil")?;
+            separator = " ";
+        }
+        _ => {
+            write!(f, "{} mil", ES_ONES[thousands])?;
+            separator = " ";
+        }
+    }
+
+    if hundreds > 0 {
+        // A hundred on its own is `cien`.
+        let word = match (hundreds, rest) {
+            (1, 0) => "cien",
+            _ => ES_HUNDREDS[hundreds],
+        };
+        write!(f, "{}{}", separator, word)?;
+        separator = " ";
+    }
+
+    let (tens, ones) = (rest / 10, rest % 10);
+    match rest {
+        0 => Ok(()),
+        1..=29 => write!(f, "{}{}", separator, ES_ONES[rest]),
+        _ if ones == 0 => write!(f, "{}{}", separator, ES_TENS[tens]),
+        _ => write!(f, "{}{} y {}", separator, ES_TENS[tens], ES_ONES[ones]),
+    }
+}
+
+fn french(f: &mut fmt::Formatter, thousands: usize, hundreds: usize, rest: usize) -> fmt::Result {
+    let mut separator = "";
+    match thousands {
+        0 => {}
+        1 => {
+            f.write_str("mille")?;
+            separator = " ";
+        }
+        _ => {
+            write!(f, "{} mille", FR_ONES[thousands])?;
+            separator = " ";
+        }
+    }
+
+    match hundreds {
+        0 => {}
+        1 => {
+            write!(f, "{}cent", separator)?;
+            separator = " ";
+        }
+        _ => {
+            // Multiplied hundreds take a plural `s` only at the end of the number.
+            let plural = if rest == 0 { "s" } else { "" };
+            write!(f, "{}{} cent{}", separator, FR_ONES[hundreds], plural)?;
+            separator = " ";
+        }
+    }
+
+    // Seventies and nineties are counted on from sixty and eighty.
+    let (tens, ones) = (rest / 10, rest % 10);
+    match rest {
+        0 => Ok(()),
+        1..=19 => write!(f, "{}{}", separator, FR_ONES[rest]),
+        71 => write!(f, "{}soixante et onze", separator),
+        70..=79 => write!(f, "{}soixante-{}", separator, FR_ONES[rest - 60]),
+        80 => write!(f, "{}quatre-vingts", separator),
+        81..=99 => write!(f, "{}quatre-vingt-{}", separator, FR_ONES[rest - 80]),
+        _ if ones == 0 => write!(f, "{}{}", separator, FR_TENS[tens]),
+        _ if ones == 1 => write!(f, "{}{} et un", separator, FR_TENS[tens]),
+        _ => write!(f, "{}{}-{}", separator, FR_TENS[tens], FR_ONES[ones]),
+    }
+}
+
+fn german(f: &mut fmt::Formatter, thousands: usize, hundreds: usize, rest: usize) -> fmt::Result {
+    // German writes the whole number as a single compound word.
+    if thousands > 0 {
+        write!(f, "{}tausend", DE_PREFIXES[thousands])?;
+    }
+
+    if hundreds > 0 {
+        write!(f, "{}hundert", DE_PREFIXES[hundreds])?;
+    }
+
+    let (tens, ones) = (rest / 10, rest % 10);
+    match rest {
+        0 => Ok(()),
+        1..=19 => f.write_str(DE_ONES[rest]),
+        _ if ones == 0 => f.write_str(DE_TENS[tens]),
+        _ => write!(f, "{}und{}", DE_PREFIXES[ones], DE_TENS[tens]),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Language;
+    use crate::Roman;
+
+    fn words(n: u16) -> String {
+        Roman::new(n).unwrap().words().to_string()
+    }
+
+    #[test]
+    fn small_values() {
+        assert_eq!("one", words(1));
+        assert_eq!("twelve", words(12));
+        assert_eq!("forty", words(40));
+        assert_eq!("ninety-nine", words(99));
+    }
+
+    #[test]
+    fn skips_empty_places() {
+        assert_eq!("three thousand", words(3000));
+        assert_eq!("two thousand five", words(2005));
+        assert_eq!("one hundred ten", words(110));
+    }
+
+    #[test]
+    fn max_value() {
+        assert_eq!("four thousand nine hundred ninety-nine", words(4999));
+    }
+
+    fn to_words(n: u16, language: Language) -> String {
+        Roman::new(n).unwrap().to_words(language).to_string()
+    }
+
+    #[test]
+    fn latin() {
+        assert_eq!("duodeviginti", to_words(18, Language::La));
+        assert_eq!("undequinquaginta", to_words(49, Language::La));
+        assert_eq!("nonaginta novem", to_words(99, Language::La));
+        assert_eq!("duo milia quingenti", to_words(2500, Language::La));
+        assert_eq!(
+            "mille nongenti octoginta quattuor",
+            to_words(1984, Language::La)
+        );
+    }
+
+    #[test]
+    fn spanish() {
+        assert_eq!("veintidós", to_words(22, Language::Es));
+        assert_eq!("cien", to_words(100, Language::Es));
+        assert_eq!("ciento uno", to_words(101, Language::Es));
+        assert_eq!("quinientos", to_words(500, Language::Es));
+        assert_eq!(
+            "cuatro mil novecientos noventa y nueve",
+            to_words(4999, Language::Es)
+        );
+    }
+
+    #[test]
+    fn french() {
+        assert_eq!("vingt et un", to_words(21, Language::Fr));
+        assert_eq!("soixante et onze", to_words(71, Language::Fr));
+        assert_eq!("soixante-dix-sept", to_words(77, Language::Fr));
+        assert_eq!("quatre-vingt-un", to_words(81, Language::Fr));
+        assert_eq!("quatre-vingt-dix-neuf", to_words(99, Language::Fr));
+        assert_eq!("deux cents", to_words(200, Language::Fr));
+        assert_eq!("deux mille deux cent un", to_words(2201, Language::Fr));
+    }
+
+    #[test]
+    fn german() {
+        assert_eq!("eins", to_words(1, Language::De));
+        assert_eq!("einundzwanzig", to_words(21, Language::De));
+        assert_eq!("einhunderteins", to_words(101, Language::De));
+        assert_eq!(
+            "viertausendneunhundertneunundneunzig",
+            to_words(4999, Language::De)
+        );
+    }
+}